@@ -3,7 +3,6 @@
  * Repo:    guhs
  * Created: 08/14/2025
  */
-
 pub fn helper() {
     println!("The helper module's function is using --help")
 }
@@ -0,0 +1,11 @@
+// 每个文件以作者信息块注释开头, 内容再包在同名的 pub mod 中
+#![allow(clippy::empty_line_after_doc_comments, clippy::module_inception)]
+
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+#[path = "maths/maths.rs"]
+pub mod maths;
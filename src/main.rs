@@ -7,7 +7,6 @@ mod helper;
  * Repo:    guhs
  * Created: 08/14/2025
  */
-
 fn main() {
     println!();
     helper()
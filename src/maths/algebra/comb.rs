@@ -5,9 +5,7 @@
  */
 
 pub mod combinatorics {
-    const ERR_NEGATIVE_VALUE: &str = "数值不能为负数";
     const ERR_INVALID_INPUT: &str = "n必须大于等于k";
-    const ERR_ZERO_FACTORIAL: &str = "负数没有阶乘";
 
     fn factorial(n: u64) -> u64 {
         (1..=n).product()
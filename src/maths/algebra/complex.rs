@@ -5,7 +5,6 @@
  */
 
 pub mod algebra {
-    use std::f64::consts::SQRT_2;
 
     #[derive(Debug, Clone, Copy)]
     pub struct Complex {
@@ -0,0 +1,263 @@
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+pub mod fractions {
+    use std::cmp::Ordering;
+    use std::fmt;
+    use std::ops::{Add, Div, Mul, Neg, Sub};
+
+    const ERR_ZERO_DENOMINATOR: &str = "分母不能为0";
+    const ERR_NOT_RATIONAL: &str = "无法在给定分母范围内用分数近似该数";
+    const ERR_OVERFLOW: &str = "分数运算超出i128范围";
+
+    // 既约分数, 分母恒为正
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Fraction {
+        num: i128,
+        den: i128,
+    }
+
+    fn gcd(a: i128, b: i128) -> i128 {
+        let (mut a, mut b) = (a.abs(), b.abs());
+        while b != 0 {
+            let t = a % b;
+            a = b;
+            b = t;
+        }
+        a
+    }
+
+    impl Fraction {
+        pub fn new(num: i128, den: i128) -> Result<Fraction, &'static str> {
+            if den == 0 {
+                return Err(ERR_ZERO_DENOMINATOR);
+            }
+            Ok(Fraction::reduced(num, den))
+        }
+
+        fn reduced(num: i128, den: i128) -> Fraction {
+            let g = gcd(num, den).max(1);
+            let sign = if den < 0 { -1 } else { 1 };
+            Fraction {
+                num: sign * num / g,
+                den: sign * den / g,
+            }
+        }
+
+        pub fn from_integer(n: i128) -> Fraction {
+            Fraction { num: n, den: 1 }
+        }
+
+        pub fn zero() -> Fraction {
+            Fraction::from_integer(0)
+        }
+
+        pub fn one() -> Fraction {
+            Fraction::from_integer(1)
+        }
+
+        pub fn numerator(&self) -> i128 {
+            self.num
+        }
+
+        pub fn denominator(&self) -> i128 {
+            self.den
+        }
+
+        pub fn is_zero(&self) -> bool {
+            self.num == 0
+        }
+
+        pub fn is_integer(&self) -> bool {
+            self.den == 1
+        }
+
+        pub fn abs(&self) -> Fraction {
+            Fraction {
+                num: self.num.abs(),
+                den: self.den,
+            }
+        }
+
+        pub fn recip(&self) -> Result<Fraction, &'static str> {
+            Fraction::new(self.den, self.num)
+        }
+
+        pub fn checked_add(self, other: Fraction) -> Result<Fraction, &'static str> {
+            let g = gcd(self.den, other.den);
+            let den = (self.den / g).checked_mul(other.den).ok_or(ERR_OVERFLOW)?;
+            let left = self.num.checked_mul(den / self.den).ok_or(ERR_OVERFLOW)?;
+            let right = other.num.checked_mul(den / other.den).ok_or(ERR_OVERFLOW)?;
+            Ok(Fraction::reduced(left.checked_add(right).ok_or(ERR_OVERFLOW)?, den))
+        }
+
+        pub fn checked_sub(self, other: Fraction) -> Result<Fraction, &'static str> {
+            self.checked_add(-other)
+        }
+
+        pub fn checked_mul(self, other: Fraction) -> Result<Fraction, &'static str> {
+            let g1 = gcd(self.num, other.den).max(1);
+            let g2 = gcd(other.num, self.den).max(1);
+            let num = (self.num / g1).checked_mul(other.num / g2).ok_or(ERR_OVERFLOW)?;
+            let den = (self.den / g2).checked_mul(other.den / g1).ok_or(ERR_OVERFLOW)?;
+            Ok(Fraction::reduced(num, den))
+        }
+
+        pub fn checked_div(self, other: Fraction) -> Result<Fraction, &'static str> {
+            self.checked_mul(other.recip()?)
+        }
+
+        pub fn powi(&self, exp: i32) -> Result<Fraction, &'static str> {
+            let base = if exp < 0 { self.recip()? } else { *self };
+            let mut result = Fraction::one();
+            for _ in 0..exp.unsigned_abs() {
+                result = result.checked_mul(base)?;
+            }
+            Ok(result)
+        }
+
+        // 连分数展开, 取误差不超过 1e-9 的第一个渐近分数
+        pub fn from_f64(x: f64, max_denominator: i128) -> Result<Fraction, &'static str> {
+            if !x.is_finite() || x.abs() > 1e18 {
+                return Err(ERR_NOT_RATIONAL);
+            }
+            let tolerance = 1e-9 * x.abs().max(1.0);
+            let (mut p0, mut q0, mut p1, mut q1) = (0i128, 1i128, 1i128, 0i128);
+            let mut rest = x;
+            loop {
+                let a = rest.floor();
+                let (p2, q2) = (a as i128 * p1 + p0, a as i128 * q1 + q0);
+                if q2 > max_denominator {
+                    return Err(ERR_NOT_RATIONAL);
+                }
+                if (x - p2 as f64 / q2 as f64).abs() <= tolerance {
+                    return Fraction::new(p2, q2);
+                }
+                (p0, q0, p1, q1) = (p1, q1, p2, q2);
+                rest = 1.0 / (rest - a);
+                if !rest.is_finite() {
+                    return Err(ERR_NOT_RATIONAL);
+                }
+            }
+        }
+
+        pub fn to_f64(&self) -> f64 {
+            self.num as f64 / self.den as f64
+        }
+    }
+
+    // 运算符在溢出时 panic, 结果可能超出 i128 时使用 checked_add、checked_mul 等
+    impl Add for Fraction {
+        type Output = Fraction;
+
+        fn add(self, other: Fraction) -> Fraction {
+            self.checked_add(other).expect(ERR_OVERFLOW)
+        }
+    }
+
+    impl Sub for Fraction {
+        type Output = Fraction;
+
+        fn sub(self, other: Fraction) -> Fraction {
+            self + (-other)
+        }
+    }
+
+    impl Mul for Fraction {
+        type Output = Fraction;
+
+        fn mul(self, other: Fraction) -> Fraction {
+            self.checked_mul(other).expect(ERR_OVERFLOW)
+        }
+    }
+
+    // 除数为0时 panic, 需要错误处理时使用 checked_div
+    impl Div for Fraction {
+        type Output = Fraction;
+
+        fn div(self, other: Fraction) -> Fraction {
+            self.checked_div(other).expect(ERR_ZERO_DENOMINATOR)
+        }
+    }
+
+    impl Neg for Fraction {
+        type Output = Fraction;
+
+        fn neg(self) -> Fraction {
+            Fraction {
+                num: -self.num,
+                den: self.den,
+            }
+        }
+    }
+
+    // 交叉相乘溢出时逐步比较连分数展开, 分母均为正
+    fn compare(mut a: (i128, i128), mut b: (i128, i128)) -> Ordering {
+        let mut reversed = false;
+        loop {
+            let (qa, ra) = (a.0.div_euclid(a.1), a.0.rem_euclid(a.1));
+            let (qb, rb) = (b.0.div_euclid(b.1), b.0.rem_euclid(b.1));
+            let order = if qa != qb { qa.cmp(&qb) } else { (ra != 0).cmp(&(rb != 0)) };
+            if order != Ordering::Equal || ra == 0 {
+                return if reversed { order.reverse() } else { order };
+            }
+            // ra/a.1 与 rb/b.1 的大小关系与其倒数相反
+            a = (a.1, ra);
+            b = (b.1, rb);
+            reversed = !reversed;
+        }
+    }
+
+    impl Ord for Fraction {
+        fn cmp(&self, other: &Fraction) -> Ordering {
+            match (self.num.checked_mul(other.den), other.num.checked_mul(self.den)) {
+                (Some(x), Some(y)) => x.cmp(&y),
+                _ => compare((self.num, self.den), (other.num, other.den)),
+            }
+        }
+    }
+
+    impl PartialOrd for Fraction {
+        fn partial_cmp(&self, other: &Fraction) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl From<i64> for Fraction {
+        fn from(n: i64) -> Fraction {
+            Fraction::from_integer(n as i128)
+        }
+    }
+
+    // 整数字面量在多个 From 实现间默认推断为 i32
+    impl From<i32> for Fraction {
+        fn from(n: i32) -> Fraction {
+            Fraction::from_integer(n as i128)
+        }
+    }
+
+    impl From<u32> for Fraction {
+        fn from(n: u32) -> Fraction {
+            Fraction::from_integer(n as i128)
+        }
+    }
+
+    impl From<usize> for Fraction {
+        fn from(n: usize) -> Fraction {
+            Fraction::from_integer(n as i128)
+        }
+    }
+
+    impl fmt::Display for Fraction {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            if self.den == 1 {
+                write!(f, "{}", self.num)
+            } else {
+                write!(f, "{}/{}", self.num, self.den)
+            }
+        }
+    }
+}
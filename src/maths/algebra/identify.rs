@@ -0,0 +1,274 @@
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+pub mod sequence_identification {
+    use crate::maths::algebra::fraction::fractions::Fraction;
+    use crate::maths::algebra::polynomial::polynomials::Polynomial;
+    use crate::maths::algebra::sequence::sequences::{arithmetic_term, geometric_term, recurrence_term};
+    use std::fmt;
+
+    const ERR_INSUFFICIENT_TERMS: &str = "至少需要给出4项才能识别并检验通项";
+    const ERR_NOT_RATIONAL: &str = "数列各项需为有理数";
+    const ERR_NO_PATTERN: &str = "未能识别出数列的规律";
+    const ERR_INDEX_OUT_OF_RANGE: &str = "项数超出通项公式的适用范围";
+    const MAX_DENOMINATOR: i128 = 1_000_000;
+    const VERIFY_TOLERANCE: f64 = 1e-9;
+
+    type Fitter = fn(&[Fraction], usize) -> Option<SequencePattern>;
+
+    // 通项均以 n 从1开始计; 递推式自第 start 项起给出初始项
+    #[derive(Debug, Clone)]
+    pub enum SequencePattern {
+        Arithmetic { a1: Fraction, d: Fraction },
+        Geometric { a1: Fraction, r: Fraction },
+        Polynomial(Polynomial),
+        LinearRecurrence { coeffs: Vec<Fraction>, initial: Vec<Fraction>, start: usize },
+    }
+
+    impl SequencePattern {
+        pub fn term(&self, n: usize) -> Result<Fraction, &'static str> {
+            if n == 0 {
+                return Err(ERR_INDEX_OUT_OF_RANGE);
+            }
+            let index = Fraction::from(n as i64);
+            match self {
+                SequencePattern::Arithmetic { a1, d } => a1.checked_add((index - Fraction::one()).checked_mul(*d)?),
+                SequencePattern::Geometric { a1, r } => a1.checked_mul(r.powi(n as i32 - 1)?),
+                SequencePattern::Polynomial(p) => p.checked_evaluate(index),
+                SequencePattern::LinearRecurrence { coeffs, initial, start } => {
+                    if n < *start {
+                        return Err(ERR_INDEX_OUT_OF_RANGE);
+                    }
+                    let mut terms = initial.clone();
+                    while terms.len() <= n - start {
+                        let len = terms.len();
+                        let next = coeffs
+                            .iter()
+                            .enumerate()
+                            .try_fold(Fraction::zero(), |acc, (j, &c)| acc.checked_add(c.checked_mul(terms[len - 1 - j])?))?;
+                        terms.push(next);
+                    }
+                    Ok(terms[n - start])
+                }
+            }
+        }
+
+        // 浮点求值, 分别交给 arithmetic_term、geometric_term、recurrence_term
+        pub fn evaluate(&self, n: usize) -> Result<f64, &'static str> {
+            match self {
+                SequencePattern::Arithmetic { a1, d } => arithmetic_term(a1.to_f64(), d.to_f64(), n as i32),
+                SequencePattern::Geometric { a1, r } => geometric_term(a1.to_f64(), r.to_f64(), n as i32),
+                SequencePattern::Polynomial(p) => Ok(p.evaluate_f64(n as f64)),
+                SequencePattern::LinearRecurrence { coeffs, initial, start } => {
+                    if n < *start {
+                        return Err(ERR_INDEX_OUT_OF_RANGE);
+                    }
+                    let initial: Vec<f64> = initial.iter().map(Fraction::to_f64).collect();
+                    let coeffs: Vec<f64> = coeffs.iter().map(Fraction::to_f64).collect();
+                    recurrence_term(&initial, &coeffs, n - start)
+                }
+            }
+        }
+    }
+
+    impl fmt::Display for SequencePattern {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                SequencePattern::Arithmetic { a1, d } => {
+                    write!(f, "a_n = {}", Polynomial::new(vec![*a1 - *d, *d]))
+                }
+                SequencePattern::Geometric { a1, r } => {
+                    let base = if *r < Fraction::zero() || !r.is_integer() { format!("({})", r) } else { r.to_string() };
+                    if *a1 == Fraction::one() {
+                        write!(f, "a_n = {}^(n-1)", base)
+                    } else {
+                        write!(f, "a_n = {}·{}^(n-1)", a1, base)
+                    }
+                }
+                SequencePattern::Polynomial(p) => write!(f, "a_n = {}", p),
+                SequencePattern::LinearRecurrence { coeffs, initial, start } => {
+                    write!(f, "a_n =")?;
+                    for (j, c) in coeffs.iter().enumerate() {
+                        if j > 0 {
+                            write!(f, " +")?;
+                        }
+                        write!(f, " ({})·a_(n-{})", c, j + 1)?;
+                    }
+                    for (i, a) in initial.iter().take(coeffs.len()).enumerate() {
+                        write!(f, ", a_{} = {}", start + i, a)?;
+                    }
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    // verified 表示拟合出的通项与留作检验的后几项完全吻合
+    #[derive(Debug, Clone)]
+    pub struct Identification {
+        pub pattern: SequencePattern,
+        pub held_out: usize,
+        pub verified: bool,
+        pub max_error: f64,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct PartialSumIdentification {
+        pub sum: Identification,
+        pub term: Identification,
+        pub first_term: Fraction,
+        pub first_term_fits: bool,
+    }
+
+    fn to_fractions(terms: &[f64]) -> Result<Vec<Fraction>, &'static str> {
+        terms
+            .iter()
+            .map(|&t| Fraction::from_f64(t, MAX_DENOMINATOR).map_err(|_| ERR_NOT_RATIONAL))
+            .collect()
+    }
+
+    // 运算溢出时视为不符合该规律
+    fn fit_arithmetic(terms: &[Fraction], start: usize) -> Option<SequencePattern> {
+        let d = terms[1].checked_sub(terms[0]).ok()?;
+        if terms.windows(2).any(|w| w[1].checked_sub(w[0]) != Ok(d)) {
+            return None;
+        }
+        let a1 = terms[0].checked_sub(Fraction::from(start as i64 - 1).checked_mul(d).ok()?).ok()?;
+        Some(SequencePattern::Arithmetic { a1, d })
+    }
+
+    fn fit_geometric(terms: &[Fraction], start: usize) -> Option<SequencePattern> {
+        if terms.iter().any(|t| t.is_zero()) {
+            return None;
+        }
+        let r = terms[1].checked_div(terms[0]).ok()?;
+        if terms.windows(2).any(|w| w[1].checked_div(w[0]) != Ok(r)) {
+            return None;
+        }
+        let a1 = terms[0].checked_div(r.powi(start as i32 - 1).ok()?).ok()?;
+        Some(SequencePattern::Geometric { a1, r })
+    }
+
+    // 逐阶差分, 第D阶差分为常数且至少有两项可供比较时, 用牛顿前向差分公式还原多项式
+    fn fit_polynomial(terms: &[Fraction], start: usize) -> Option<SequencePattern> {
+        let mut leading = Vec::new();
+        let mut row = terms.to_vec();
+        loop {
+            leading.push(row[0]);
+            if row.len() < 2 {
+                return None;
+            }
+            if row.windows(2).all(|w| w[0] == w[1]) {
+                break;
+            }
+            row = row.windows(2).map(|w| w[1].checked_sub(w[0])).collect::<Result<_, _>>().ok()?;
+        }
+        // a_n = Σ Δ^j·C(m-1, j), m = n - start + 1
+        let mut result = Polynomial::new(Vec::new());
+        let mut basis = Polynomial::constant(Fraction::one());
+        for (j, &delta) in leading.iter().enumerate() {
+            result = result.checked_add(&basis.checked_scale(delta).ok()?).ok()?;
+            let factor = Polynomial::new(vec![Fraction::from(-1 - j as i64), Fraction::one()]);
+            basis = basis.checked_mul(&factor).ok()?.checked_scale(Fraction::one() / Fraction::from(j as i64 + 1)).ok()?;
+        }
+        let shift = Fraction::from(1 - start as i64);
+        Some(SequencePattern::Polynomial(result.checked_compose_linear(Fraction::one(), shift).ok()?))
+    }
+
+    // Berlekamp-Massey: 求满足全部已知项的最短线性递推
+    fn fit_recurrence(terms: &[Fraction], start: usize) -> Option<SequencePattern> {
+        let mut c = vec![Fraction::one()];
+        let mut b = vec![Fraction::one()];
+        let mut length = 0usize;
+        let mut shift = 1usize;
+        let mut last_discrepancy = Fraction::one();
+        for n in 0..terms.len() {
+            let discrepancy = (1..=length)
+                .try_fold(terms[n], |acc, i| acc.checked_add(c.get(i).copied().unwrap_or_else(Fraction::zero).checked_mul(terms[n - i])?))
+                .ok()?;
+            if discrepancy.is_zero() {
+                shift += 1;
+                continue;
+            }
+            let factor = discrepancy.checked_div(last_discrepancy).ok()?;
+            let previous = c.clone();
+            if c.len() < b.len() + shift {
+                c.resize(b.len() + shift, Fraction::zero());
+            }
+            for (i, &bi) in b.iter().enumerate() {
+                c[i + shift] = c[i + shift].checked_sub(factor.checked_mul(bi).ok()?).ok()?;
+            }
+            if 2 * length <= n {
+                length = n + 1 - length;
+                b = previous;
+                last_discrepancy = discrepancy;
+                shift = 1;
+            } else {
+                shift += 1;
+            }
+        }
+        if length == 0 || 2 * length > terms.len() - 1 {
+            return None;
+        }
+        c.resize(length + 1, Fraction::zero());
+        Some(SequencePattern::LinearRecurrence {
+            coeffs: c[1..].iter().map(|&x| -x).collect(),
+            initial: terms[..length].to_vec(),
+            start,
+        })
+    }
+
+    // 留出项上的最大绝对误差, 无法求值时记为无穷大
+    fn held_out_error(pattern: &SequencePattern, terms: &[Fraction], start: usize) -> f64 {
+        terms
+            .iter()
+            .enumerate()
+            .map(|(i, &t)| pattern.term(start + i).and_then(|v| v.checked_sub(t)).map_or(f64::INFINITY, |e| e.to_f64().abs()))
+            .fold(0.0, f64::max)
+    }
+
+    fn identify_from(terms: &[Fraction], start: usize) -> Result<Identification, &'static str> {
+        if terms.len() < 4 {
+            return Err(ERR_INSUFFICIENT_TERMS);
+        }
+        let held_out = if terms.len() >= 8 { 2 } else { 1 };
+        let (training, testing) = terms.split_at(terms.len() - held_out);
+        let test_start = start + training.len();
+        let fitters: [Fitter; 4] = [fit_arithmetic, fit_geometric, fit_polynomial, fit_recurrence];
+        // 输入经有理逼近而来, 误差按留出项的量级放宽
+        let tolerance = VERIFY_TOLERANCE * testing.iter().map(|t| t.to_f64().abs()).fold(1.0, f64::max);
+
+        let mut fallback: Option<(SequencePattern, f64)> = None;
+        for fitter in fitters {
+            let pattern = match fitter(training, start) {
+                Some(pattern) => pattern,
+                None => continue,
+            };
+            let max_error = held_out_error(&pattern, testing, test_start);
+            if max_error <= tolerance {
+                return Ok(Identification { pattern, held_out, verified: true, max_error });
+            }
+            fallback.get_or_insert((pattern, max_error));
+        }
+        let (pattern, max_error) = fallback.ok_or(ERR_NO_PATTERN)?;
+        Ok(Identification { pattern, held_out, verified: false, max_error })
+    }
+
+    pub fn identify_sequence(terms: &[f64]) -> Result<Identification, &'static str> {
+        identify_from(&to_fractions(terms)?, 1)
+    }
+
+    // 已知 S_1, S_2, ...: a_1 = S_1, n >= 2 时 a_n = S_n - S_(n-1)
+    pub fn identify_from_partial_sums(sums: &[f64]) -> Result<PartialSumIdentification, &'static str> {
+        let sums = to_fractions(sums)?;
+        let sum = identify_from(&sums, 1)?;
+        let differences: Vec<Fraction> = sums.windows(2).map(|w| w[1].checked_sub(w[0])).collect::<Result<_, _>>()?;
+        let term = identify_from(&differences, 2)?;
+        let first_term = sums[0];
+        let first_term_fits = term.pattern.term(1).is_ok_and(|a1| a1 == first_term);
+        Ok(PartialSumIdentification { sum, term, first_term, first_term_fits })
+    }
+}
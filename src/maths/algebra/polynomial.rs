@@ -0,0 +1,188 @@
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+pub mod polynomials {
+    use crate::maths::algebra::fraction::fractions::Fraction;
+    use std::fmt;
+    use std::ops::{Add, Mul, Neg, Sub};
+
+    const ERR_OVERFLOW: &str = "多项式系数超出i128范围";
+
+    // 有理系数多项式, coeffs[i] 为 n^i 的系数
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Polynomial {
+        coeffs: Vec<Fraction>,
+    }
+
+    impl Polynomial {
+        pub fn new(coeffs: Vec<Fraction>) -> Polynomial {
+            let mut p = Polynomial { coeffs };
+            p.trim();
+            p
+        }
+
+        pub fn from_integers(coeffs: &[i64]) -> Polynomial {
+            Polynomial::new(coeffs.iter().map(|&c| Fraction::from(c)).collect())
+        }
+
+        pub fn constant(c: Fraction) -> Polynomial {
+            Polynomial::new(vec![c])
+        }
+
+        pub fn monomial(c: Fraction, degree: usize) -> Polynomial {
+            let mut coeffs = vec![Fraction::zero(); degree + 1];
+            coeffs[degree] = c;
+            Polynomial::new(coeffs)
+        }
+
+        fn trim(&mut self) {
+            while self.coeffs.last().is_some_and(|c| c.is_zero()) {
+                self.coeffs.pop();
+            }
+        }
+
+        pub fn coeffs(&self) -> &[Fraction] {
+            &self.coeffs
+        }
+
+        pub fn coefficient(&self, i: usize) -> Fraction {
+            self.coeffs.get(i).copied().unwrap_or_else(Fraction::zero)
+        }
+
+        pub fn is_zero(&self) -> bool {
+            self.coeffs.is_empty()
+        }
+
+        // 零多项式的次数记为 None
+        pub fn degree(&self) -> Option<usize> {
+            self.coeffs.len().checked_sub(1)
+        }
+
+        pub fn evaluate(&self, x: Fraction) -> Fraction {
+            self.checked_evaluate(x).expect(ERR_OVERFLOW)
+        }
+
+        pub fn checked_evaluate(&self, x: Fraction) -> Result<Fraction, &'static str> {
+            self.coeffs.iter().rev().try_fold(Fraction::zero(), |acc, &c| acc.checked_mul(x)?.checked_add(c))
+        }
+
+        pub fn evaluate_f64(&self, x: f64) -> f64 {
+            self.coeffs.iter().rev().fold(0.0, |acc, c| acc * x + c.to_f64())
+        }
+
+        pub fn scale(&self, k: Fraction) -> Polynomial {
+            self.checked_scale(k).expect(ERR_OVERFLOW)
+        }
+
+        pub fn checked_scale(&self, k: Fraction) -> Result<Polynomial, &'static str> {
+            Ok(Polynomial::new(self.coeffs.iter().map(|&c| c.checked_mul(k)).collect::<Result<_, _>>()?))
+        }
+
+        pub fn checked_add(&self, other: &Polynomial) -> Result<Polynomial, &'static str> {
+            let len = self.coeffs.len().max(other.coeffs.len());
+            Ok(Polynomial::new((0..len).map(|i| self.coefficient(i).checked_add(other.coefficient(i))).collect::<Result<_, _>>()?))
+        }
+
+        pub fn checked_mul(&self, other: &Polynomial) -> Result<Polynomial, &'static str> {
+            if self.is_zero() || other.is_zero() {
+                return Ok(Polynomial::new(Vec::new()));
+            }
+            let mut coeffs = vec![Fraction::zero(); self.coeffs.len() + other.coeffs.len() - 1];
+            for (i, &a) in self.coeffs.iter().enumerate() {
+                for (j, &b) in other.coeffs.iter().enumerate() {
+                    coeffs[i + j] = coeffs[i + j].checked_add(a.checked_mul(b)?)?;
+                }
+            }
+            Ok(Polynomial::new(coeffs))
+        }
+
+        // p(a·n + b)
+        pub fn compose_linear(&self, a: Fraction, b: Fraction) -> Polynomial {
+            self.checked_compose_linear(a, b).expect(ERR_OVERFLOW)
+        }
+
+        pub fn checked_compose_linear(&self, a: Fraction, b: Fraction) -> Result<Polynomial, &'static str> {
+            let linear = Polynomial::new(vec![b, a]);
+            self.coeffs
+                .iter()
+                .rev()
+                .try_fold(Polynomial::new(Vec::new()), |acc, &c| acc.checked_mul(&linear)?.checked_add(&Polynomial::constant(c)))
+        }
+
+        pub fn to_string_with(&self, variable: &str) -> String {
+            if self.is_zero() {
+                return "0".to_string();
+            }
+            let mut out = String::new();
+            for (i, c) in self.coeffs.iter().enumerate().rev() {
+                if c.is_zero() {
+                    continue;
+                }
+                let negative = *c < Fraction::zero();
+                if out.is_empty() {
+                    if negative {
+                        out.push('-');
+                    }
+                } else {
+                    out.push_str(if negative { " - " } else { " + " });
+                }
+                let magnitude = c.abs();
+                let show_coefficient = i == 0 || magnitude != Fraction::one();
+                if show_coefficient {
+                    if magnitude.is_integer() || i == 0 {
+                        out.push_str(&magnitude.to_string());
+                    } else {
+                        out.push_str(&format!("({})", magnitude));
+                    }
+                }
+                match i {
+                    0 => {}
+                    1 => out.push_str(variable),
+                    _ => out.push_str(&format!("{}^{}", variable, i)),
+                }
+            }
+            out
+        }
+    }
+
+    impl fmt::Display for Polynomial {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.to_string_with("n"))
+        }
+    }
+
+    impl Add for &Polynomial {
+        type Output = Polynomial;
+
+        fn add(self, other: &Polynomial) -> Polynomial {
+            self.checked_add(other).expect(ERR_OVERFLOW)
+        }
+    }
+
+    impl Sub for &Polynomial {
+        type Output = Polynomial;
+
+        fn sub(self, other: &Polynomial) -> Polynomial {
+            self + &(-other)
+        }
+    }
+
+    impl Mul for &Polynomial {
+        type Output = Polynomial;
+
+        fn mul(self, other: &Polynomial) -> Polynomial {
+            self.checked_mul(other).expect(ERR_OVERFLOW)
+        }
+    }
+
+    impl Neg for &Polynomial {
+        type Output = Polynomial;
+
+        fn neg(self) -> Polynomial {
+            Polynomial::new(self.coeffs.iter().map(|&c| -c).collect())
+        }
+    }
+}
@@ -5,10 +5,478 @@
  */
 
 pub mod sequences {
+    use crate::maths::algebra::complex::algebra::{add, multiply, Complex};
+    use crate::maths::algebra::fraction::fractions::Fraction;
+    use crate::maths::algebra::polynomial::polynomials::Polynomial;
+    use std::fmt;
+
     const ERR_NEGATIVE_N: &str = "项数n不能为负数";
     const ERR_ZERO_COMMON_RATIO: &str = "等比数列公比不能为0";
-    const ERR_INVALID_INDEX: &str = "索引必须大于等于0";
     const ERR_DIVISION_BY_ZERO: &str = "除数不能为0";
+    const ERR_EMPTY_INITIAL: &str = "初始项不能为空";
+    const ERR_ZERO_LAST_COEFF: &str = "递推式最后一项系数不能为0";
+    const ERR_ROOTS_NOT_CONVERGED: &str = "特征方程求根未收敛";
+    const ERR_SINGULAR_SYSTEM: &str = "待定系数方程组奇异, 无法求解";
+    const ERR_UNIT_RATIO: &str = "公比为1时应直接按等差数列求和";
+    const ERR_ZERO_TERM: &str = "数列中出现为0的项, 裂项无意义";
+    const ERR_ZERO_GROUP: &str = "分组大小必须大于0";
+    const ERR_INVALID_RANGE: &str = "项数范围需满足1 <= from <= to";
+    const ERR_MISMATCHED_COEFFS: &str = "系数数量必须与初始项数量一致";
+    const ERR_NOT_ENOUGH_TERMS: &str = "数列的项数不足";
+    const MONOTONE_TOLERANCE: f64 = 1e-12;
+    const ROOT_TOLERANCE: f64 = 1e-3;
+    const ZERO: Complex = Complex { real: 0.0, imaginary: 0.0 };
+    const ONE: Complex = Complex { real: 1.0, imaginary: 0.0 };
+
+    // 错位相减: S_n = (p·n + q)·r^n - q
+    #[derive(Debug, Clone, Copy)]
+    pub struct ArithmeticGeometricSum {
+        pub p: f64,
+        pub q: f64,
+        pub r: f64,
+    }
+
+    impl ArithmeticGeometricSum {
+        pub fn evaluate(&self, n: i32) -> f64 {
+            (self.p * n as f64 + self.q) * self.r.powi(n) - self.q
+        }
+    }
+
+    impl fmt::Display for ArithmeticGeometricSum {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "S_n = ({}n{})·{}^n{}",
+                format_real(self.p),
+                format_signed(self.q),
+                format_complex(real(self.r)),
+                format_signed(-self.q)
+            )
+        }
+    }
+
+    // 交错求和 Σ(-1)^(k+1)·f(k), 按n的奇偶分别给出关于n的多项式
+    #[derive(Debug, Clone)]
+    pub struct AlternatingSum {
+        pub even: Polynomial,
+        pub odd: Polynomial,
+    }
+
+    impl AlternatingSum {
+        pub fn evaluate(&self, n: u64) -> Result<Fraction, &'static str> {
+            let x = Fraction::from(n as i64);
+            if n.is_multiple_of(2) { self.even.checked_evaluate(x) } else { self.odd.checked_evaluate(x) }
+        }
+    }
+
+    impl fmt::Display for AlternatingSum {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "S_n = {} (n为偶数); S_n = {} (n为奇数)", self.even, self.odd)
+        }
+    }
+
+    // Σ1/(k(k+d)) = 1/d·(1 + 1/2 + ... + 1/d - 1/(n+1) - ... - 1/(n+d))
+    #[derive(Debug, Clone, Copy)]
+    pub struct ReciprocalProductSum {
+        pub d: u32,
+    }
+
+    impl ReciprocalProductSum {
+        pub fn evaluate(&self, n: u64) -> Result<Fraction, &'static str> {
+            let inverse = |k: u64| Fraction::new(1, k as i128);
+            let d = self.d as u64;
+            let sum = (1..=d).try_fold(Fraction::zero(), |acc, j| acc.checked_add(inverse(j)?)?.checked_sub(inverse(n + j)?))?;
+            sum.checked_div(Fraction::from(d as i64))
+        }
+    }
+
+    impl fmt::Display for ReciprocalProductSum {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let head = (1..=self.d).map(|j| if j == 1 { "1".to_string() } else { format!("1/{}", j) }).collect::<Vec<_>>().join(" + ");
+            let tail = (1..=self.d).map(|j| format!("1/(n+{})", j)).collect::<Vec<_>>().join(" - ");
+            if self.d == 1 {
+                write!(f, "S_n = {} - {}", head, tail)
+            } else {
+                write!(f, "S_n = 1/{}·({} - {})", self.d, head, tail)
+            }
+        }
+    }
+
+    // S_n = n / (a1·(a1 + dn))
+    #[derive(Debug, Clone, Copy)]
+    pub struct ArithmeticReciprocalSum {
+        pub a1: f64,
+        pub d: f64,
+    }
+
+    impl ArithmeticReciprocalSum {
+        pub fn evaluate(&self, n: i32) -> f64 {
+            n as f64 / (self.a1 * (self.a1 + n as f64 * self.d))
+        }
+    }
+
+    impl fmt::Display for ArithmeticReciprocalSum {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "S_n = n / ({}·({}{}n))",
+                format_complex(real(self.a1)),
+                format_real(self.a1),
+                format_signed(self.d)
+            )
+        }
+    }
+
+    // S_n = √(n+1) - 1
+    #[derive(Debug, Clone, Copy)]
+    pub struct SqrtTelescopingSum;
+
+    impl SqrtTelescopingSum {
+        pub fn evaluate(&self, n: u64) -> f64 {
+            ((n + 1) as f64).sqrt() - 1.0
+        }
+    }
+
+    impl fmt::Display for SqrtTelescopingSum {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "S_n = √(n+1) - 1")
+        }
+    }
+
+    // 分组求和: group_term 为第 m 组之和 G_m, group_sum 为前 m 组之和, 均为关于 m 的多项式
+    #[derive(Debug, Clone)]
+    pub struct GroupedSum {
+        pub pieces: Vec<Polynomial>,
+        pub group_term: Polynomial,
+        pub group_sum: Polynomial,
+    }
+
+    impl GroupedSum {
+        pub fn group_size(&self) -> u64 {
+            self.pieces.len() as u64
+        }
+
+        // 项数从1开始
+        pub fn term(&self, k: u64) -> Result<Fraction, &'static str> {
+            if k == 0 {
+                return Err(ERR_NEGATIVE_N);
+            }
+            self.value(k)
+        }
+
+        fn value(&self, k: u64) -> Result<Fraction, &'static str> {
+            let piece = &self.pieces[((k - 1) % self.group_size()) as usize];
+            piece.checked_evaluate(Fraction::from(k as i64))
+        }
+
+        fn sum_range(&self, from: u64, to: u64) -> Result<Fraction, &'static str> {
+            (from..=to).try_fold(Fraction::zero(), |acc, k| acc.checked_add(self.value(k)?))
+        }
+
+        // 前 n 项依次分组后各组之和, 最后一组可能不满
+        pub fn groups(&self, n: u64) -> Result<Vec<Fraction>, &'static str> {
+            let g = self.group_size();
+            (0..n.div_ceil(g)).map(|m| self.sum_range(m * g + 1, ((m + 1) * g).min(n))).collect()
+        }
+
+        // 完整的组用 group_sum 求和, 余下不足一组的项逐项相加
+        pub fn evaluate(&self, n: u64) -> Result<Fraction, &'static str> {
+            let g = self.group_size();
+            let full = n / g;
+            let rest = self.sum_range(full * g + 1, n)?;
+            self.group_sum.checked_evaluate(Fraction::from(full as i64))?.checked_add(rest)
+        }
+    }
+
+    impl fmt::Display for GroupedSum {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let g = self.group_size();
+            let prefix = if g == 1 { "m".to_string() } else { format!("{}m", g) };
+            write!(
+                f,
+                "G_m = {}; S_{} = {}",
+                self.group_term.to_string_with("m"),
+                prefix,
+                self.group_sum.to_string_with("m")
+            )
+        }
+    }
+
+    // a_n = Σ coefficient·m^power·root^m, m = n - start
+    #[derive(Debug, Clone, Copy)]
+    pub struct ClosedFormTerm {
+        pub coefficient: Complex,
+        pub power: u32,
+        pub root: Complex,
+    }
+
+    // start 为首项的下标, 公式对 n >= start 成立
+    #[derive(Debug, Clone)]
+    pub struct ClosedForm {
+        pub terms: Vec<ClosedFormTerm>,
+        pub start: usize,
+    }
+
+    impl ClosedForm {
+        // n < start 时公式无定义, 返回 NaN
+        pub fn evaluate_complex(&self, n: usize) -> Complex {
+            let Some(m) = n.checked_sub(self.start) else {
+                return Complex { real: f64::NAN, imaginary: f64::NAN };
+            };
+            self.terms.iter().fold(ZERO, |acc, term| {
+                let mut value = multiply(term.coefficient, complex_powi(term.root, m));
+                value = scale(value, (m as f64).powi(term.power as i32));
+                add(acc, value)
+            })
+        }
+
+        pub fn evaluate(&self, n: usize) -> f64 {
+            self.evaluate_complex(n).real
+        }
+    }
+
+    impl fmt::Display for ClosedForm {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a_n =")?;
+            if self.terms.is_empty() {
+                return write!(f, " 0");
+            }
+            let m = if self.start == 0 { "n".to_string() } else { format!("(n-{})", self.start) };
+            for (i, term) in self.terms.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " +")?;
+                }
+                write!(f, " {}", format_complex(term.coefficient))?;
+                match term.power {
+                    0 => {}
+                    1 => write!(f, "·{}", m)?,
+                    p => write!(f, "·{}^{}", m, p)?,
+                }
+                if !is_one(term.root) {
+                    write!(f, "·{}^{}", format_complex(term.root), m)?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    fn real(x: f64) -> Complex {
+        Complex { real: x, imaginary: 0.0 }
+    }
+
+    fn subtract(a: Complex, b: Complex) -> Complex {
+        Complex {
+            real: a.real - b.real,
+            imaginary: a.imaginary - b.imaginary,
+        }
+    }
+
+    fn scale(a: Complex, k: f64) -> Complex {
+        Complex {
+            real: a.real * k,
+            imaginary: a.imaginary * k,
+        }
+    }
+
+    fn norm(a: Complex) -> f64 {
+        a.real.hypot(a.imaginary)
+    }
+
+    fn checked_divide(a: Complex, b: Complex) -> Option<Complex> {
+        let denominator = b.real * b.real + b.imaginary * b.imaginary;
+        if denominator < 1e-300 {
+            return None;
+        }
+        Some(Complex {
+            real: (a.real * b.real + a.imaginary * b.imaginary) / denominator,
+            imaginary: (a.imaginary * b.real - a.real * b.imaginary) / denominator,
+        })
+    }
+
+    fn complex_powi(a: Complex, n: usize) -> Complex {
+        let mut result = ONE;
+        let mut base = a;
+        let mut e = n;
+        while e > 0 {
+            if e & 1 == 1 {
+                result = multiply(result, base);
+            }
+            base = multiply(base, base);
+            e >>= 1;
+        }
+        result
+    }
+
+    fn is_one(a: Complex) -> bool {
+        (a.real - 1.0).abs() < 1e-12 && a.imaginary.abs() < 1e-12
+    }
+
+    fn format_real(x: f64) -> String {
+        if (x - x.round()).abs() < 1e-9 {
+            format!("{}", x.round() + 0.0)
+        } else {
+            let s = format!("{:.6}", x);
+            s.trim_end_matches('0').trim_end_matches('.').to_string()
+        }
+    }
+
+    fn format_signed(x: f64) -> String {
+        if x < 0.0 {
+            format!(" - {}", format_real(-x))
+        } else {
+            format!(" + {}", format_real(x))
+        }
+    }
+
+    fn format_complex(a: Complex) -> String {
+        if a.imaginary.abs() < 1e-9 {
+            let s = format_real(a.real);
+            if a.real < 0.0 { format!("({})", s) } else { s }
+        } else {
+            let sign = if a.imaginary < 0.0 { "-" } else { "+" };
+            format!("({}{}{}i)", format_real(a.real), sign, format_real(a.imaginary.abs()))
+        }
+    }
+
+    fn eval_polynomial(coeffs: &[f64], x: Complex) -> Complex {
+        coeffs.iter().fold(ZERO, |acc, &c| add(multiply(acc, x), real(c)))
+    }
+
+    fn derive_polynomial(coeffs: &[f64]) -> Vec<f64> {
+        let degree = coeffs.len().saturating_sub(1);
+        coeffs[..degree]
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| c * (degree - i) as f64)
+            .collect()
+    }
+
+    // 多项式(系数按降幂排列, 首项为1)的全部复根, Durand-Kerner 迭代
+    fn polynomial_roots(coeffs: &[f64]) -> Result<Vec<Complex>, &'static str> {
+        let k = coeffs.len() - 1;
+        let seed = Complex { real: 0.4, imaginary: 0.9 };
+        let bound = 1.0 + coeffs[1..].iter().fold(0.0_f64, |m, c| m.max(c.abs()));
+        let mut roots: Vec<Complex> = (0..k).map(|i| scale(complex_powi(seed, i), bound)).collect();
+        for _ in 0..5000 {
+            let mut delta = 0.0_f64;
+            for i in 0..k {
+                let mut denominator = ONE;
+                for j in 0..k {
+                    if i != j {
+                        denominator = multiply(denominator, subtract(roots[i], roots[j]));
+                    }
+                }
+                let step = match checked_divide(eval_polynomial(coeffs, roots[i]), denominator) {
+                    Some(step) => step,
+                    None => continue,
+                };
+                roots[i] = subtract(roots[i], step);
+                delta = delta.max(norm(step));
+            }
+            if delta < 1e-15 * bound {
+                break;
+            }
+        }
+        if roots.iter().any(|r| !r.real.is_finite() || !r.imaginary.is_finite()) {
+            return Err(ERR_ROOTS_NOT_CONVERGED);
+        }
+        Ok(roots)
+    }
+
+    // m 重根是 p^(m-1) 的单根: 对聚在一起的近似根用牛顿法精化, 验证失败则仍按单根处理
+    fn refine_cluster(coeffs: &[f64], cluster: &[Complex]) -> Option<Complex> {
+        let m = cluster.len();
+        let mut derivatives = vec![coeffs.to_vec()];
+        for _ in 0..m {
+            let next = derive_polynomial(derivatives.last()?);
+            derivatives.push(next);
+        }
+        let mut x = scale(cluster.iter().fold(ZERO, |acc, &r| add(acc, r)), 1.0 / m as f64);
+        for _ in 0..50 {
+            let step = checked_divide(eval_polynomial(&derivatives[m - 1], x), eval_polynomial(&derivatives[m], x))?;
+            x = subtract(x, step);
+            if norm(step) < 1e-15 * norm(x).max(1.0) {
+                break;
+            }
+        }
+        let magnitude = norm(x).max(1.0);
+        let is_root = derivatives[..m].iter().all(|d| {
+            let size = d.iter().fold(0.0_f64, |s, c| s.max(c.abs())) * magnitude.powi(d.len() as i32);
+            norm(eval_polynomial(d, x)) < 1e-8 * size.max(1.0)
+        });
+        if is_root { Some(x) } else { None }
+    }
+
+    fn group_roots(coeffs: &[f64], roots: Vec<Complex>) -> Vec<(Complex, u32)> {
+        let mut clusters: Vec<Vec<Complex>> = Vec::new();
+        for r in roots {
+            match clusters.iter_mut().find(|c| norm(subtract(c[0], r)) < ROOT_TOLERANCE * norm(r).max(1.0)) {
+                Some(cluster) => cluster.push(r),
+                None => clusters.push(vec![r]),
+            }
+        }
+        let clean = |x: f64| if (x - x.round()).abs() < 1e-9 { x.round() } else { x };
+        let mut grouped = Vec::new();
+        for cluster in clusters {
+            match refine_cluster(coeffs, &cluster) {
+                Some(r) => grouped.push((Complex { real: clean(r.real), imaginary: clean(r.imaginary) }, cluster.len() as u32)),
+                None => grouped.extend(cluster.into_iter().map(|r| (r, 1))),
+            }
+        }
+        grouped
+    }
+
+    fn solve_complex(mut matrix: Vec<Vec<Complex>>, mut rhs: Vec<Complex>) -> Result<Vec<Complex>, &'static str> {
+        let n = rhs.len();
+        for col in 0..n {
+            let pivot = (col..n)
+                .max_by(|&a, &b| norm(matrix[a][col]).total_cmp(&norm(matrix[b][col])))
+                .unwrap_or(col);
+            if norm(matrix[pivot][col]) < 1e-12 {
+                return Err(ERR_SINGULAR_SYSTEM);
+            }
+            matrix.swap(col, pivot);
+            rhs.swap(col, pivot);
+            let pivot_row = matrix[col].clone();
+            for row in col + 1..n {
+                let factor = checked_divide(matrix[row][col], pivot_row[col]).ok_or(ERR_SINGULAR_SYSTEM)?;
+                for (value, &p) in matrix[row].iter_mut().zip(&pivot_row).skip(col) {
+                    *value = subtract(*value, multiply(factor, p));
+                }
+                rhs[row] = subtract(rhs[row], multiply(factor, rhs[col]));
+            }
+        }
+        let mut solution = vec![ZERO; n];
+        for row in (0..n).rev() {
+            let mut sum = rhs[row];
+            for j in row + 1..n {
+                sum = subtract(sum, multiply(matrix[row][j], solution[j]));
+            }
+            solution[row] = checked_divide(sum, matrix[row][row]).ok_or(ERR_SINGULAR_SYSTEM)?;
+        }
+        Ok(solution)
+    }
+
+    fn fit_closed_form(roots: &[(Complex, u32)], terms: &[f64]) -> Result<ClosedForm, &'static str> {
+        let basis: Vec<(Complex, u32)> = roots.iter().flat_map(|&(r, m)| (0..m).map(move |p| (r, p))).collect();
+        let matrix: Vec<Vec<Complex>> = (0..basis.len())
+            .map(|n| {
+                basis
+                    .iter()
+                    .map(|&(r, p)| scale(complex_powi(r, n), (n as f64).powi(p as i32)))
+                    .collect()
+            })
+            .collect();
+        let rhs: Vec<Complex> = terms[..basis.len()].iter().map(|&a| real(a)).collect();
+        let coefficients = solve_complex(matrix, rhs)?;
+        let terms = basis
+            .iter()
+            .zip(coefficients)
+            .filter(|(_, c)| norm(*c) > 1e-12)
+            .map(|(&(root, power), coefficient)| ClosedFormTerm { coefficient, power, root })
+            .collect();
+        Ok(ClosedForm { terms, start: 0 })
+    }
 
     pub fn arithmetic_term(a1: f64, d: f64, n: i32) -> Result<f64, &'static str> {
         if n < 1 {
@@ -51,12 +519,12 @@ pub mod sequences {
     pub fn recurrence_term(initial: &[f64], coeffs: &[f64], n: usize) -> Result<f64, &'static str> {
         let k = initial.len();
         if coeffs.len() != k {
-            return Err("系数数量必须与初始项数量一致");
+            return Err(ERR_MISMATCHED_COEFFS);
+        }
+        if k == 0 {
+            return Err(ERR_EMPTY_INITIAL);
         }
         if n < k {
-            if n >= initial.len() {
-                return Err(ERR_INVALID_INDEX);
-            }
             return Ok(initial[n]);
         }
 
@@ -70,4 +538,518 @@ pub mod sequences {
         }
         Ok(terms[n])
     }
+
+    // 特征根法: 与 recurrence_term 相同, a_0 = initial[0], a_n = Σ coeffs[j]·a_(n-1-j)
+    pub fn recurrence_closed_form(initial: &[f64], coeffs: &[f64]) -> Result<ClosedForm, &'static str> {
+        recurrence_closed_form_nonhomogeneous(initial, coeffs, &[], 1.0)
+    }
+
+    // a_n = Σ coeffs[j]·a_(n-1-j) + P(n)·r^n (n >= k), poly 为 P 的系数, 按升幂排列
+    pub fn recurrence_closed_form_nonhomogeneous(initial: &[f64], coeffs: &[f64], poly: &[f64], r: f64) -> Result<ClosedForm, &'static str> {
+        let k = initial.len();
+        if k == 0 {
+            return Err(ERR_EMPTY_INITIAL);
+        }
+        if coeffs.len() != k {
+            return Err(ERR_MISMATCHED_COEFFS);
+        }
+        if coeffs[k - 1] == 0.0 {
+            return Err(ERR_ZERO_LAST_COEFF);
+        }
+        if r == 0.0 {
+            return Err(ERR_ZERO_COMMON_RATIO);
+        }
+        let degree = poly.iter().rposition(|&c| c != 0.0);
+        let characteristic: Vec<f64> = std::iter::once(1.0).chain(coeffs.iter().map(|&c| -c)).collect();
+        let mut grouped = group_roots(&characteristic, polynomial_roots(&characteristic)?);
+        // 非齐次项 P(n)·r^n 相当于特征根 r 的重数增加 deg P + 1
+        if let Some(d) = degree {
+            let extra = d as u32 + 1;
+            match grouped.iter_mut().find(|(root, _)| norm(subtract(*root, real(r))) < ROOT_TOLERANCE * r.abs().max(1.0)) {
+                Some(group) => {
+                    group.0 = real(r);
+                    group.1 += extra;
+                }
+                None => grouped.push((real(r), extra)),
+            }
+        }
+
+        let order = k + degree.map_or(0, |d| d + 1);
+        let mut terms = initial.to_vec();
+        for n in k..order {
+            let mut next: f64 = coeffs.iter().enumerate().map(|(j, &c)| c * terms[n - 1 - j]).sum();
+            let p: f64 = poly.iter().rev().fold(0.0, |acc, &c| acc * n as f64 + c);
+            next += p * r.powi(n as i32);
+            terms.push(next);
+        }
+        fit_closed_form(&grouped, &terms)
+    }
+
+    // a_1 给定, a_(n+1) = p·a_n + q, 通项按 n 从1开始: a_n = (a1 - λ)·p^(n-1) + λ
+    pub fn first_order_closed_form(a1: f64, p: f64, q: f64) -> Result<ClosedForm, &'static str> {
+        let terms = if p == 1.0 {
+            vec![
+                ClosedFormTerm { coefficient: real(a1), power: 0, root: ONE },
+                ClosedFormTerm { coefficient: real(q), power: 1, root: ONE },
+            ]
+        } else {
+            let lambda = q / (1.0 - p);
+            vec![
+                ClosedFormTerm { coefficient: real(a1 - lambda), power: 0, root: real(p) },
+                ClosedFormTerm { coefficient: real(lambda), power: 0, root: ONE },
+            ]
+        };
+        Ok(ClosedForm { terms: terms.into_iter().filter(|t| t.coefficient.real != 0.0).collect(), start: 1 })
+    }
+
+    // a_1 给定, a_(n+1) = p·a_n + q·r^n, 通项按 n 从1开始
+    pub fn first_order_exponential_closed_form(a1: f64, p: f64, q: f64, r: f64) -> Result<ClosedForm, &'static str> {
+        if r == 0.0 {
+            return Err(ERR_ZERO_COMMON_RATIO);
+        }
+        let terms = if r == p {
+            vec![
+                ClosedFormTerm { coefficient: real(q), power: 1, root: real(p) },
+                ClosedFormTerm { coefficient: real(a1), power: 0, root: real(p) },
+            ]
+        } else {
+            let lambda = q / (r - p);
+            vec![
+                ClosedFormTerm { coefficient: real(lambda * r), power: 0, root: real(r) },
+                ClosedFormTerm { coefficient: real(a1 - lambda * r), power: 0, root: real(p) },
+            ]
+        };
+        Ok(ClosedForm { terms: terms.into_iter().filter(|t| t.coefficient.real != 0.0).collect(), start: 1 })
+    }
+
+    fn binomial(n: u32, k: u32) -> Result<Fraction, &'static str> {
+        (0..k).try_fold(Fraction::one(), |acc, i| {
+            acc.checked_mul(Fraction::from((n - i) as i64))?.checked_div(Fraction::from((i + 1) as i64))
+        })
+    }
+
+    // 取 B₁ = +1/2 的伯努利数 B₀..B_count-1
+    fn bernoulli_numbers(count: usize) -> Result<Vec<Fraction>, &'static str> {
+        let mut b: Vec<Fraction> = Vec::with_capacity(count);
+        for m in 0..count as u32 {
+            if m == 0 {
+                b.push(Fraction::one());
+                continue;
+            }
+            let sum = (0..m).try_fold(Fraction::zero(), |acc, j| acc.checked_add(binomial(m + 1, j)?.checked_mul(b[j as usize])?))?;
+            b.push((-sum).checked_div(Fraction::from((m + 1) as i64))?);
+        }
+        if count > 1 {
+            b[1] = -b[1];
+        }
+        Ok(b)
+    }
+
+    // Faulhaber 公式: Σk^p (k = 1..n) = 1/(p+1)·Σ C(p+1, j)·B_j·n^(p+1-j)
+    pub fn power_sum_polynomial(p: u32) -> Result<Polynomial, &'static str> {
+        let b = bernoulli_numbers(p as usize + 1)?;
+        let mut coeffs = vec![Fraction::zero(); p as usize + 2];
+        let scale = Fraction::from((p + 1) as i64);
+        for j in 0..=p {
+            coeffs[(p + 1 - j) as usize] = binomial(p + 1, j)?.checked_mul(b[j as usize])?.checked_div(scale)?;
+        }
+        Ok(Polynomial::new(coeffs))
+    }
+
+    pub fn power_sum(p: u32, n: u64) -> Result<(Polynomial, Fraction), &'static str> {
+        let closed_form = power_sum_polynomial(p)?;
+        let value = closed_form.checked_evaluate(Fraction::from(n as i64))?;
+        Ok((closed_form, value))
+    }
+
+    pub fn square_sum(n: u64) -> Result<(Polynomial, Fraction), &'static str> {
+        power_sum(2, n)
+    }
+
+    pub fn cube_sum(n: u64) -> Result<(Polynomial, Fraction), &'static str> {
+        power_sum(3, n)
+    }
+
+    // Σf(k) (k = 1..n), 由 Faulhaber 公式逐项求和
+    pub fn polynomial_sum(f: &Polynomial) -> Result<Polynomial, &'static str> {
+        f.coeffs()
+            .iter()
+            .enumerate()
+            .try_fold(Polynomial::new(Vec::new()), |acc, (p, &c)| acc.checked_add(&power_sum_polynomial(p as u32)?.checked_scale(c)?))
+    }
+
+    // Σ(a1 + (k-1)d)·b1·r^(k-1), 即等差数列与等比数列对应项之积的前n项和
+    pub fn arithmetic_geometric_sum(a1: f64, d: f64, b1: f64, r: f64, n: i32) -> Result<(ArithmeticGeometricSum, f64), &'static str> {
+        if n < 1 {
+            return Err(ERR_NEGATIVE_N);
+        }
+        if r == 0.0 {
+            return Err(ERR_ZERO_COMMON_RATIO);
+        }
+        if r == 1.0 {
+            return Err(ERR_UNIT_RATIO);
+        }
+        let one_minus_r = 1.0 - r;
+        let p = -b1 * d / one_minus_r;
+        let q = -b1 * (d / (one_minus_r * one_minus_r) + (a1 - d) / one_minus_r);
+        let sum = ArithmeticGeometricSum { p, q, r };
+        Ok((sum, sum.evaluate(n)))
+    }
+
+    // 裂项相消: 1/(k(k+d)) = 1/d·(1/k - 1/(k+d))
+    pub fn reciprocal_product_sum(d: u32, n: u64) -> Result<(ReciprocalProductSum, Fraction), &'static str> {
+        if d == 0 {
+            return Err(ERR_DIVISION_BY_ZERO);
+        }
+        let sum = ReciprocalProductSum { d };
+        Ok((sum, sum.evaluate(n)?))
+    }
+
+    // 等差数列 a_k 的 Σ1/(a_k·a_(k+1)) = n / (a1·a_(n+1))
+    pub fn arithmetic_reciprocal_sum(a1: f64, d: f64, n: i32) -> Result<(ArithmeticReciprocalSum, f64), &'static str> {
+        if n < 1 {
+            return Err(ERR_NEGATIVE_N);
+        }
+        if d == 0.0 {
+            return Err(ERR_DIVISION_BY_ZERO);
+        }
+        if (1..=n + 1).any(|k| (a1 + (k - 1) as f64 * d).abs() < 1e-12) {
+            return Err(ERR_ZERO_TERM);
+        }
+        let sum = ArithmeticReciprocalSum { a1, d };
+        Ok((sum, sum.evaluate(n)))
+    }
+
+    // 分母有理化: 1/(√k + √(k+1)) = √(k+1) - √k
+    pub fn sqrt_telescoping_sum(n: u64) -> (SqrtTelescopingSum, f64) {
+        (SqrtTelescopingSum, SqrtTelescopingSum.evaluate(n))
+    }
+
+    // 相邻两项配对: f(2m-1) - f(2m) 为关于m的多项式, 求和后代回 m = n/2 或 (n-1)/2
+    pub fn alternating_sum(f: &Polynomial) -> Result<AlternatingSum, &'static str> {
+        let two = Fraction::from(2);
+        let pair = f.checked_compose_linear(two, -Fraction::one())?.checked_add(&-&f.checked_compose_linear(two, Fraction::zero())?)?;
+        let pair_sum = polynomial_sum(&pair)?;
+        let half = Fraction::one() / two;
+        let even = pair_sum.checked_compose_linear(half, Fraction::zero())?;
+        let odd = pair_sum.checked_compose_linear(half, -half)?.checked_add(f)?;
+        Ok(AlternatingSum { even, odd })
+    }
+
+    // 分组求和: 每 g 项为一组, 组内第 j 项的通项为 pieces[j - 1](k)
+    pub fn grouped_sum(pieces: &[Polynomial]) -> Result<GroupedSum, &'static str> {
+        if pieces.is_empty() {
+            return Err(ERR_ZERO_GROUP);
+        }
+        let g = Fraction::from(pieces.len() as i64);
+        // 第 m 组的第 j 项为 k = g·m - g + j
+        let group_term = pieces.iter().enumerate().try_fold(Polynomial::new(Vec::new()), |acc, (j, piece)| {
+            acc.checked_add(&piece.checked_compose_linear(g, Fraction::from(j as i64 + 1) - g)?)
+        })?;
+        let group_sum = polynomial_sum(&group_term)?;
+        Ok(GroupedSum {
+            pieces: pieces.to_vec(),
+            group_term,
+            group_sum,
+        })
+    }
+
+    // 分组求和的常见情形: a_n 为等差数列与等比数列对应项之和
+    pub fn arithmetic_plus_geometric_sum(a1: f64, d: f64, b1: f64, r: f64, n: i32) -> Result<f64, &'static str> {
+        Ok(arithmetic_sum(a1, d, n)? + geometric_sum(b1, r, n)?)
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Monotonicity {
+        Increasing,
+        Decreasing,
+        NonDecreasing,
+        NonIncreasing,
+        Constant,
+        NotMonotonic,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Bounds {
+        pub min: f64,
+        pub argmin: usize,
+        pub max: f64,
+        pub argmax: usize,
+    }
+
+    // 项数 n 从1开始; terms() 按需逐项产生, 实现者可覆盖为增量计算
+    pub trait Sequence {
+        fn term(&self, n: usize) -> Result<f64, &'static str>;
+
+        fn terms(&self) -> Box<dyn Iterator<Item = f64> + '_> {
+            Box::new((1..).map_while(move |n| self.term(n).ok()))
+        }
+
+        fn partial_sum(&self, n: usize) -> Result<f64, &'static str> {
+            if n == 0 {
+                return Ok(0.0);
+            }
+            self.partial_sums().nth(n - 1).ok_or(ERR_NOT_ENOUGH_TERMS)
+        }
+
+        fn partial_sums(&self) -> Box<dyn Iterator<Item = f64> + '_> {
+            Box::new(self.terms().scan(0.0, |sum, a| {
+                *sum += a;
+                Some(*sum)
+            }))
+        }
+
+        // 在第 from 项到第 to 项之间判断单调性
+        fn monotonicity(&self, from: usize, to: usize) -> Result<Monotonicity, &'static str> {
+            let values = range_terms(self, from, to)?;
+            let (mut rising, mut falling, mut flat) = (false, false, false);
+            for w in values.windows(2) {
+                let tolerance = MONOTONE_TOLERANCE * w[0].abs().max(w[1].abs()).max(1.0);
+                if w[1] - w[0] > tolerance {
+                    rising = true;
+                } else if w[0] - w[1] > tolerance {
+                    falling = true;
+                } else {
+                    flat = true;
+                }
+            }
+            Ok(match (rising, falling) {
+                (true, true) => Monotonicity::NotMonotonic,
+                (true, false) if !flat => Monotonicity::Increasing,
+                (true, false) => Monotonicity::NonDecreasing,
+                (false, true) if !flat => Monotonicity::Decreasing,
+                (false, true) => Monotonicity::NonIncreasing,
+                (false, false) => Monotonicity::Constant,
+            })
+        }
+
+        // 最大项与最小项, 相同时取项数较小者
+        fn bounds(&self, from: usize, to: usize) -> Result<Bounds, &'static str> {
+            let values = range_terms(self, from, to)?;
+            let mut bounds = Bounds { min: values[0], argmin: from, max: values[0], argmax: from };
+            for (i, &v) in values.iter().enumerate().skip(1) {
+                if v < bounds.min {
+                    bounds.min = v;
+                    bounds.argmin = from + i;
+                }
+                if v > bounds.max {
+                    bounds.max = v;
+                    bounds.argmax = from + i;
+                }
+            }
+            Ok(bounds)
+        }
+
+        fn into_partial_sums(self) -> PartialSumSequence<Self>
+        where
+            Self: Sized,
+        {
+            PartialSumSequence { inner: self }
+        }
+    }
+
+    fn range_terms<S: Sequence + ?Sized>(sequence: &S, from: usize, to: usize) -> Result<Vec<f64>, &'static str> {
+        if from == 0 || from > to {
+            return Err(ERR_INVALID_RANGE);
+        }
+        let values: Vec<f64> = sequence.terms().skip(from - 1).take(to - from + 1).collect();
+        if values.len() < to - from + 1 {
+            return Err(ERR_NOT_ENOUGH_TERMS);
+        }
+        Ok(values)
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    pub struct ArithmeticSequence {
+        pub a1: f64,
+        pub d: f64,
+    }
+
+    impl ArithmeticSequence {
+        pub fn new(a1: f64, d: f64) -> ArithmeticSequence {
+            ArithmeticSequence { a1, d }
+        }
+    }
+
+    impl Sequence for ArithmeticSequence {
+        fn term(&self, n: usize) -> Result<f64, &'static str> {
+            arithmetic_term(self.a1, self.d, index(n)?)
+        }
+
+        fn partial_sum(&self, n: usize) -> Result<f64, &'static str> {
+            if n == 0 {
+                return Ok(0.0);
+            }
+            arithmetic_sum(self.a1, self.d, index(n)?)
+        }
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    pub struct GeometricSequence {
+        pub a1: f64,
+        pub r: f64,
+    }
+
+    impl GeometricSequence {
+        pub fn new(a1: f64, r: f64) -> Result<GeometricSequence, &'static str> {
+            if r == 0.0 {
+                return Err(ERR_ZERO_COMMON_RATIO);
+            }
+            Ok(GeometricSequence { a1, r })
+        }
+    }
+
+    impl Sequence for GeometricSequence {
+        fn term(&self, n: usize) -> Result<f64, &'static str> {
+            geometric_term(self.a1, self.r, index(n)?)
+        }
+
+        fn partial_sum(&self, n: usize) -> Result<f64, &'static str> {
+            if n == 0 {
+                return Ok(0.0);
+            }
+            geometric_sum(self.a1, self.r, index(n)?)
+        }
+    }
+
+    // n 超出 i32 范围时视为项数非法
+    fn index(n: usize) -> Result<i32, &'static str> {
+        i32::try_from(n).map_err(|_| ERR_NEGATIVE_N)
+    }
+
+    // a_1..a_k 为 initial, 之后 a_n = Σ coeffs[j]·a_(n-1-j)
+    #[derive(Debug, Clone)]
+    pub struct RecurrenceSequence {
+        initial: Vec<f64>,
+        coeffs: Vec<f64>,
+    }
+
+    impl RecurrenceSequence {
+        pub fn new(initial: &[f64], coeffs: &[f64]) -> Result<RecurrenceSequence, &'static str> {
+            if initial.is_empty() {
+                return Err(ERR_EMPTY_INITIAL);
+            }
+            if coeffs.len() != initial.len() {
+                return Err(ERR_MISMATCHED_COEFFS);
+            }
+            Ok(RecurrenceSequence { initial: initial.to_vec(), coeffs: coeffs.to_vec() })
+        }
+
+        // 与 term 一致, 通项从 a_1 起计
+        pub fn closed_form(&self) -> Result<ClosedForm, &'static str> {
+            let form = recurrence_closed_form(&self.initial, &self.coeffs)?;
+            Ok(ClosedForm { start: 1, ..form })
+        }
+    }
+
+    impl Sequence for RecurrenceSequence {
+        fn term(&self, n: usize) -> Result<f64, &'static str> {
+            if n == 0 {
+                return Err(ERR_NEGATIVE_N);
+            }
+            recurrence_term(&self.initial, &self.coeffs, n - 1)
+        }
+
+        // 只保留最近 k 项, 逐项递推
+        fn terms(&self) -> Box<dyn Iterator<Item = f64> + '_> {
+            let mut window = self.initial.clone();
+            Box::new(std::iter::from_fn(move || {
+                let next = self.coeffs.iter().zip(window.iter().rev()).map(|(c, a)| c * a).sum();
+                window.push(next);
+                Some(window.remove(0))
+            }))
+        }
+    }
+
+    pub struct ClosureSequence<F>
+    where
+        F: Fn(usize) -> f64,
+    {
+        general_term: F,
+    }
+
+    impl<F> ClosureSequence<F>
+    where
+        F: Fn(usize) -> f64,
+    {
+        pub fn new(general_term: F) -> ClosureSequence<F> {
+            ClosureSequence { general_term }
+        }
+    }
+
+    impl<F> Sequence for ClosureSequence<F>
+    where
+        F: Fn(usize) -> f64,
+    {
+        fn term(&self, n: usize) -> Result<f64, &'static str> {
+            if n == 0 {
+                return Err(ERR_NEGATIVE_N);
+            }
+            Ok((self.general_term)(n))
+        }
+    }
+
+    // 由前 n 项和 S_n 给出的数列: a_1 = S_1, n >= 2 时 a_n = S_n - S_(n-1)
+    pub struct SumDefinedSequence<F>
+    where
+        F: Fn(usize) -> f64,
+    {
+        sum: F,
+    }
+
+    impl<F> SumDefinedSequence<F>
+    where
+        F: Fn(usize) -> f64,
+    {
+        pub fn new(sum: F) -> SumDefinedSequence<F> {
+            SumDefinedSequence { sum }
+        }
+
+        // S_0 = 0 时 a_n = S_n - S_(n-1) 对 n = 1 也成立, 通项不必分段
+        pub fn first_term_matches(&self) -> bool {
+            let s0 = (self.sum)(0);
+            s0.abs() <= MONOTONE_TOLERANCE * (self.sum)(1).abs().max(1.0)
+        }
+    }
+
+    impl<F> Sequence for SumDefinedSequence<F>
+    where
+        F: Fn(usize) -> f64,
+    {
+        fn term(&self, n: usize) -> Result<f64, &'static str> {
+            match n {
+                0 => Err(ERR_NEGATIVE_N),
+                1 => Ok((self.sum)(1)),
+                _ => Ok((self.sum)(n) - (self.sum)(n - 1)),
+            }
+        }
+
+        fn partial_sum(&self, n: usize) -> Result<f64, &'static str> {
+            Ok(if n == 0 { 0.0 } else { (self.sum)(n) })
+        }
+    }
+
+    // 以原数列的前 n 项和 S_n 为第 n 项
+    pub struct PartialSumSequence<S: Sequence> {
+        inner: S,
+    }
+
+    impl<S: Sequence> PartialSumSequence<S> {
+        pub fn inner(&self) -> &S {
+            &self.inner
+        }
+    }
+
+    impl<S: Sequence> Sequence for PartialSumSequence<S> {
+        fn term(&self, n: usize) -> Result<f64, &'static str> {
+            if n == 0 {
+                return Err(ERR_NEGATIVE_N);
+            }
+            self.inner.partial_sum(n)
+        }
+
+        fn terms(&self) -> Box<dyn Iterator<Item = f64> + '_> {
+            self.inner.partial_sums()
+        }
+    }
 }
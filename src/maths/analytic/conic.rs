@@ -5,11 +5,248 @@
  */
 
 pub mod conic_sections {
+    use crate::maths::analytic::line::lines::Line;
+
     const ERR_INVALID_ELLIPSE_PARAMS: &str = "椭圆参数需满足a > b > 0";
     const ERR_INVALID_HYPERBOLA_PARAMS: &str = "双曲线参数需满足a > 0, b > 0";
     const ERR_INVALID_PARABOLA_PARAM: &str = "抛物线参数p需大于0";
     const ERR_INVALID_ECCENTRICITY: &str = "离心率需满足椭圆0 < e < 1, 双曲线e > 1, 抛物线e = 1";
     const ERR_DIVISION_BY_ZERO: &str = "除数不能为0";
+    const ERR_LINE_ON_CONIC: &str = "直线整体落在曲线上, 交点不唯一";
+    const ERR_NOT_QUADRATIC: &str = "二次项系数A, B, C不能同时为0";
+    const ERR_POINT_NOT_ON_CONIC: &str = "该点不在曲线上";
+    const ERR_POINT_INSIDE: &str = "该点在曲线内部, 不存在切线";
+    const ERR_SINGULAR_POINT: &str = "该点为曲线的奇点, 切线不确定";
+    const ERR_SINGULAR_PARAMETER: &str = "参数t使cos t = 0, 双曲线参数方程无意义";
+    const EPSILON: f64 = 1e-9;
+
+    // 二次曲线一般式 Ax² + Bxy + Cy² + Dx + Ey + F = 0
+    #[derive(Debug, Clone, Copy)]
+    pub struct GeneralConic {
+        pub a: f64,
+        pub b: f64,
+        pub c: f64,
+        pub d: f64,
+        pub e: f64,
+        pub f: f64,
+    }
+
+    impl GeneralConic {
+        pub fn value(&self, x: f64, y: f64) -> f64 {
+            self.a * x * x + self.b * x * y + self.c * y * y + self.d * x + self.e * y + self.f
+        }
+    }
+
+    pub trait Conic {
+        fn general_form(&self) -> GeneralConic;
+    }
+
+    impl Conic for GeneralConic {
+        fn general_form(&self) -> GeneralConic {
+            *self
+        }
+    }
+
+    // x²/a² + y²/b² = 1, a > b > 0
+    #[derive(Debug, Clone, Copy)]
+    pub struct Ellipse {
+        pub a: f64,
+        pub b: f64,
+    }
+
+    // x²/a² - y²/b² = 1
+    #[derive(Debug, Clone, Copy)]
+    pub struct Hyperbola {
+        pub a: f64,
+        pub b: f64,
+    }
+
+    // y² = 2px
+    #[derive(Debug, Clone, Copy)]
+    pub struct Parabola {
+        pub p: f64,
+    }
+
+    impl Ellipse {
+        pub fn new(a: f64, b: f64) -> Result<Ellipse, &'static str> {
+            if a <= b || b <= 0.0 {
+                return Err(ERR_INVALID_ELLIPSE_PARAMS);
+            }
+            Ok(Ellipse { a, b })
+        }
+
+        pub fn focal_distance(&self) -> f64 {
+            (self.a * self.a - self.b * self.b).sqrt()
+        }
+
+        pub fn foci(&self) -> [(f64, f64); 2] {
+            let c = self.focal_distance();
+            [(-c, 0.0), (c, 0.0)]
+        }
+    }
+
+    impl Hyperbola {
+        pub fn new(a: f64, b: f64) -> Result<Hyperbola, &'static str> {
+            if a <= 0.0 || b <= 0.0 {
+                return Err(ERR_INVALID_HYPERBOLA_PARAMS);
+            }
+            Ok(Hyperbola { a, b })
+        }
+
+        pub fn focal_distance(&self) -> f64 {
+            (self.a * self.a + self.b * self.b).sqrt()
+        }
+
+        pub fn foci(&self) -> [(f64, f64); 2] {
+            let c = self.focal_distance();
+            [(-c, 0.0), (c, 0.0)]
+        }
+
+        pub fn asymptote_slopes(&self) -> (f64, f64) {
+            (self.b / self.a, -self.b / self.a)
+        }
+    }
+
+    impl Parabola {
+        pub fn new(p: f64) -> Result<Parabola, &'static str> {
+            if p <= 0.0 {
+                return Err(ERR_INVALID_PARABOLA_PARAM);
+            }
+            Ok(Parabola { p })
+        }
+
+        pub fn focus(&self) -> (f64, f64) {
+            (self.p / 2.0, 0.0)
+        }
+
+        pub fn directrix(&self) -> Line {
+            Line::vertical(-self.p / 2.0)
+        }
+    }
+
+    impl Conic for Ellipse {
+        fn general_form(&self) -> GeneralConic {
+            GeneralConic {
+                a: 1.0 / (self.a * self.a),
+                b: 0.0,
+                c: 1.0 / (self.b * self.b),
+                d: 0.0,
+                e: 0.0,
+                f: -1.0,
+            }
+        }
+    }
+
+    impl Conic for Hyperbola {
+        fn general_form(&self) -> GeneralConic {
+            GeneralConic {
+                a: 1.0 / (self.a * self.a),
+                b: 0.0,
+                c: -1.0 / (self.b * self.b),
+                d: 0.0,
+                e: 0.0,
+                f: -1.0,
+            }
+        }
+    }
+
+    impl Conic for Parabola {
+        fn general_form(&self) -> GeneralConic {
+            GeneralConic {
+                a: 0.0,
+                b: 0.0,
+                c: 1.0,
+                d: -2.0 * self.p,
+                e: 0.0,
+                f: 0.0,
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum LinePosition {
+        Secant,
+        Tangent,
+        Separate,
+        // 二次项系数为0 (与渐近线或对称轴平行), 只有一个交点
+        SinglePoint,
+    }
+
+    // 从曲线外一点作切线: 切点、切线以及切点弦所在直线(极线)
+    #[derive(Debug, Clone)]
+    pub struct TangentsFromPoint {
+        pub points: Vec<(f64, f64)>,
+        pub lines: Vec<Line>,
+        pub polar: Line,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum ConicKind {
+        Ellipse,
+        Circle,
+        Hyperbola,
+        Parabola,
+        Point,
+        Empty,
+        IntersectingLines,
+        ParallelLines,
+        CoincidentLines,
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    pub enum CanonicalForm {
+        Ellipse(Ellipse),
+        Circle(f64),
+        Hyperbola(Hyperbola),
+        Parabola(Parabola),
+        Degenerate,
+    }
+
+    // 原坐标 (x, y) = R(rotation)·(x', y') + translation
+    #[derive(Debug, Clone, Copy)]
+    pub struct ConicTransform {
+        pub rotation: f64,
+        pub translation: (f64, f64),
+    }
+
+    impl ConicTransform {
+        pub fn to_canonical(&self, x: f64, y: f64) -> (f64, f64) {
+            let (sin, cos) = self.rotation.sin_cos();
+            let dx = x - self.translation.0;
+            let dy = y - self.translation.1;
+            (dx * cos + dy * sin, -dx * sin + dy * cos)
+        }
+
+        pub fn from_canonical(&self, x: f64, y: f64) -> (f64, f64) {
+            let (sin, cos) = self.rotation.sin_cos();
+            (
+                x * cos - y * sin + self.translation.0,
+                x * sin + y * cos + self.translation.1,
+            )
+        }
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    pub struct ClassifiedConic {
+        pub kind: ConicKind,
+        pub form: CanonicalForm,
+        pub transform: ConicTransform,
+    }
+
+    // 联立后的一元二次方程 qa·t² + qb·t + qc = 0, variable 为消元后保留的变量
+    #[derive(Debug, Clone)]
+    pub struct ChordAnalysis {
+        pub variable: char,
+        pub quadratic: (f64, f64, f64),
+        pub discriminant: f64,
+        pub position: LinePosition,
+        pub root_sum: Option<f64>,
+        pub root_product: Option<f64>,
+        pub points: Vec<(f64, f64)>,
+        pub chord_length: Option<f64>,
+        pub midpoint: Option<(f64, f64)>,
+        pub dot_product: Option<f64>,
+    }
 
     pub fn eccentricity_ellipse(a: f64, b: f64) -> Result<f64, &'static str> {
         if a <= b || b <= 0.0 {
@@ -42,6 +279,9 @@ pub mod conic_sections {
         if a <= 0.0 || b <= 0.0 {
             return Err(ERR_INVALID_HYPERBOLA_PARAMS);
         }
+        if t.cos().abs() < 1e-12 {
+            return Err(ERR_SINGULAR_PARAMETER);
+        }
         Ok((a / t.cos(), b * t.tan()))
     }
 
@@ -76,12 +316,32 @@ pub mod conic_sections {
         if a <= b || b <= 0.0 {
             return Err(ERR_INVALID_ELLIPSE_PARAMS);
         }
-        if x0 == 0.0 {
+        if y0 == 0.0 {
             return Err(ERR_DIVISION_BY_ZERO);
         }
         Ok(-(b * b * x0) / (a * a * y0))
     }
 
+    pub fn point_difference_method_hyperbola(x0: f64, y0: f64, a: f64, b: f64) -> Result<f64, &'static str> {
+        if a <= 0.0 || b <= 0.0 {
+            return Err(ERR_INVALID_HYPERBOLA_PARAMS);
+        }
+        if y0 == 0.0 {
+            return Err(ERR_DIVISION_BY_ZERO);
+        }
+        Ok((b * b * x0) / (a * a * y0))
+    }
+
+    pub fn point_difference_method_parabola(y0: f64, p: f64) -> Result<f64, &'static str> {
+        if p <= 0.0 {
+            return Err(ERR_INVALID_PARABOLA_PARAM);
+        }
+        if y0 == 0.0 {
+            return Err(ERR_DIVISION_BY_ZERO);
+        }
+        Ok(p / y0)
+    }
+
     pub fn tangent_chord_ellipse(x0: f64, y0: f64, a: f64, b: f64) -> Result<(f64, f64, f64), &'static str> {
         if a <= b || b <= 0.0 {
             return Err(ERR_INVALID_ELLIPSE_PARAMS);
@@ -102,4 +362,342 @@ pub mod conic_sections {
         }
         Ok((1.0, -y0, p * x0))
     }
+
+    pub fn line_conic_intersection<C: Conic>(conic: &C, line: &Line) -> Result<ChordAnalysis, &'static str> {
+        let g = conic.general_form();
+        let eliminate_y = !line.is_vertical();
+        // 消去y: y = kx + m; 竖直直线 x = s 时消去x
+        let (variable, qa, qb, qc, k, m) = if eliminate_y {
+            let k = -line.a / line.b;
+            let m = -line.c / line.b;
+            (
+                'x',
+                g.a + g.b * k + g.c * k * k,
+                g.b * m + 2.0 * g.c * k * m + g.d + g.e * k,
+                g.c * m * m + g.e * m + g.f,
+                k,
+                m,
+            )
+        } else {
+            let s = -line.c / line.a;
+            (
+                'y',
+                g.c,
+                g.b * s + g.e,
+                g.a * s * s + g.d * s + g.f,
+                0.0,
+                s,
+            )
+        };
+        let to_point = |t: f64| if eliminate_y { (t, k * t + m) } else { (m, t) };
+        let scale = qa.abs().max(qb.abs()).max(qc.abs()).max(1.0);
+
+        if qa.abs() < EPSILON * scale {
+            if qb.abs() < EPSILON * scale {
+                if qc.abs() < EPSILON * scale {
+                    return Err(ERR_LINE_ON_CONIC);
+                }
+                return Ok(ChordAnalysis {
+                    variable,
+                    quadratic: (0.0, qb, qc),
+                    discriminant: 0.0,
+                    position: LinePosition::Separate,
+                    root_sum: None,
+                    root_product: None,
+                    points: Vec::new(),
+                    chord_length: None,
+                    midpoint: None,
+                    dot_product: None,
+                });
+            }
+            return Ok(ChordAnalysis {
+                variable,
+                quadratic: (0.0, qb, qc),
+                discriminant: 0.0,
+                position: LinePosition::SinglePoint,
+                root_sum: None,
+                root_product: None,
+                points: vec![to_point(-qc / qb)],
+                chord_length: None,
+                midpoint: None,
+                dot_product: None,
+            });
+        }
+
+        let discriminant = qb * qb - 4.0 * qa * qc;
+        let position = if discriminant.abs() < EPSILON * (qb * qb).max((4.0 * qa * qc).abs()).max(EPSILON) {
+            LinePosition::Tangent
+        } else if discriminant > 0.0 {
+            LinePosition::Secant
+        } else {
+            LinePosition::Separate
+        };
+        let sum = -qb / qa;
+        let product = qc / qa;
+
+        let mut analysis = ChordAnalysis {
+            variable,
+            quadratic: (qa, qb, qc),
+            discriminant,
+            position,
+            root_sum: Some(sum),
+            root_product: Some(product),
+            points: Vec::new(),
+            chord_length: None,
+            midpoint: None,
+            dot_product: None,
+        };
+        if position == LinePosition::Separate {
+            return Ok(analysis);
+        }
+
+        let root_gap = if position == LinePosition::Tangent { 0.0 } else { discriminant.sqrt() / qa.abs() };
+        analysis.points = if position == LinePosition::Tangent {
+            vec![to_point(sum / 2.0)]
+        } else {
+            let t1 = (-qb - discriminant.sqrt()) / (2.0 * qa);
+            let t2 = (-qb + discriminant.sqrt()) / (2.0 * qa);
+            vec![to_point(t1), to_point(t2)]
+        };
+        // 弦长 √(1+k²)|x₁-x₂|, 竖直直线时即 |y₁-y₂|
+        analysis.chord_length = Some(if eliminate_y { (1.0 + k * k).sqrt() * root_gap } else { root_gap });
+        analysis.midpoint = Some(to_point(sum / 2.0));
+        // OA·OB = x₁x₂ + y₁y₂, 由韦达定理展开
+        analysis.dot_product = Some(if eliminate_y {
+            product + k * k * product + k * m * sum + m * m
+        } else {
+            m * m + product
+        });
+        Ok(analysis)
+    }
+
+    fn rotate_conic(g: &GeneralConic, theta: f64) -> GeneralConic {
+        let (s, c) = theta.sin_cos();
+        GeneralConic {
+            a: g.a * c * c + g.b * c * s + g.c * s * s,
+            b: 0.0,
+            c: g.a * s * s - g.b * c * s + g.c * c * c,
+            d: g.d * c + g.e * s,
+            e: -g.d * s + g.e * c,
+            f: g.f,
+        }
+    }
+
+    fn rotate_point(x: f64, y: f64, theta: f64) -> (f64, f64) {
+        let (s, c) = theta.sin_cos();
+        (x * c - y * s, x * s + y * c)
+    }
+
+    pub fn classify_conic(conic: &GeneralConic) -> Result<ClassifiedConic, &'static str> {
+        let scale = conic.a.abs().max(conic.b.abs()).max(conic.c.abs());
+        if scale < EPSILON {
+            return Err(ERR_NOT_QUADRATIC);
+        }
+        let g = GeneralConic {
+            a: conic.a / scale,
+            b: conic.b / scale,
+            c: conic.c / scale,
+            d: conic.d / scale,
+            e: conic.e / scale,
+            f: conic.f / scale,
+        };
+        let mut theta = 0.5 * g.b.atan2(g.a - g.c);
+        let mut r = rotate_conic(&g, theta);
+
+        if r.a.abs() > EPSILON && r.c.abs() > EPSILON {
+            // 有心曲线: 配方后 A'X² + C'Y² + F' = 0
+            let h = -r.d / (2.0 * r.a);
+            let k = -r.e / (2.0 * r.c);
+            let f = r.f - r.a * h * h - r.c * k * k;
+            let translation = rotate_point(h, k, theta);
+            let (kind, form) = if f.abs() < EPSILON {
+                if r.a * r.c > 0.0 {
+                    (ConicKind::Point, CanonicalForm::Degenerate)
+                } else {
+                    (ConicKind::IntersectingLines, CanonicalForm::Degenerate)
+                }
+            } else {
+                let ra = -f / r.a;
+                let rc = -f / r.c;
+                if r.a * r.c > 0.0 {
+                    if ra < 0.0 {
+                        (ConicKind::Empty, CanonicalForm::Degenerate)
+                    } else if (ra - rc).abs() < EPSILON * ra.max(1.0) {
+                        (ConicKind::Circle, CanonicalForm::Circle(ra.sqrt()))
+                    } else if ra > rc {
+                        (ConicKind::Ellipse, CanonicalForm::Ellipse(Ellipse { a: ra.sqrt(), b: rc.sqrt() }))
+                    } else {
+                        theta += std::f64::consts::FRAC_PI_2;
+                        (ConicKind::Ellipse, CanonicalForm::Ellipse(Ellipse { a: rc.sqrt(), b: ra.sqrt() }))
+                    }
+                } else if ra > 0.0 {
+                    (ConicKind::Hyperbola, CanonicalForm::Hyperbola(Hyperbola { a: ra.sqrt(), b: (-rc).sqrt() }))
+                } else {
+                    theta += std::f64::consts::FRAC_PI_2;
+                    (ConicKind::Hyperbola, CanonicalForm::Hyperbola(Hyperbola { a: rc.sqrt(), b: (-ra).sqrt() }))
+                }
+            };
+            return Ok(ClassifiedConic {
+                kind,
+                form,
+                transform: ConicTransform { rotation: theta, translation },
+            });
+        }
+
+        // 抛物型: 旋转使平方项落在y'上, 得 C'Y² + D'X + E'Y + F = 0
+        if r.c.abs() < EPSILON {
+            theta += std::f64::consts::FRAC_PI_2;
+            r = rotate_conic(&g, theta);
+        }
+        let k = -r.e / (2.0 * r.c);
+        let f = r.f - r.c * k * k;
+        if r.d.abs() < EPSILON {
+            let kind = if f.abs() < EPSILON {
+                ConicKind::CoincidentLines
+            } else if -f / r.c > 0.0 {
+                ConicKind::ParallelLines
+            } else {
+                ConicKind::Empty
+            };
+            return Ok(ClassifiedConic {
+                kind,
+                form: CanonicalForm::Degenerate,
+                transform: ConicTransform { rotation: theta, translation: rotate_point(0.0, k, theta) },
+            });
+        }
+        let h = -f / r.d;
+        let translation = rotate_point(h, k, theta);
+        let mut p = -r.d / (2.0 * r.c);
+        if p < 0.0 {
+            theta += std::f64::consts::PI;
+            p = -p;
+        }
+        Ok(ClassifiedConic {
+            kind: ConicKind::Parabola,
+            form: CanonicalForm::Parabola(Parabola { p }),
+            transform: ConicTransform { rotation: theta, translation },
+        })
+    }
+
+    fn on_conic(g: &GeneralConic, x: f64, y: f64) -> bool {
+        let scale = g.a.abs().max(g.b.abs()).max(g.c.abs()).max(g.d.abs()).max(g.e.abs()).max(g.f.abs());
+        g.value(x, y).abs() < EPSILON * scale * (1.0 + x * x + y * y)
+    }
+
+    // 极线 Ax₀x + B(x₀y + y₀x)/2 + Cy₀y + D(x + x₀)/2 + E(y + y₀)/2 + F = 0, 点在曲线上时即为切线
+    pub fn polar_line<C: Conic>(conic: &C, x0: f64, y0: f64) -> Result<Line, &'static str> {
+        let g = conic.general_form();
+        Line::new(
+            g.a * x0 + g.b * y0 / 2.0 + g.d / 2.0,
+            g.b * x0 / 2.0 + g.c * y0 + g.e / 2.0,
+            g.d * x0 / 2.0 + g.e * y0 / 2.0 + g.f,
+        )
+        .map_err(|_| ERR_SINGULAR_POINT)
+    }
+
+    pub fn tangent_at<C: Conic>(conic: &C, x0: f64, y0: f64) -> Result<Line, &'static str> {
+        if !on_conic(&conic.general_form(), x0, y0) {
+            return Err(ERR_POINT_NOT_ON_CONIC);
+        }
+        polar_line(conic, x0, y0)
+    }
+
+    pub fn normal_at<C: Conic>(conic: &C, x0: f64, y0: f64) -> Result<Line, &'static str> {
+        let tangent = tangent_at(conic, x0, y0)?;
+        Line::new(tangent.b, -tangent.a, tangent.a * y0 - tangent.b * x0)
+    }
+
+    pub fn tangents_from_point<C: Conic>(conic: &C, px: f64, py: f64) -> Result<TangentsFromPoint, &'static str> {
+        let polar = polar_line(conic, px, py)?;
+        if on_conic(&conic.general_form(), px, py) {
+            return Ok(TangentsFromPoint {
+                points: vec![(px, py)],
+                lines: vec![polar],
+                polar,
+            });
+        }
+        let chord = line_conic_intersection(conic, &polar)?;
+        if chord.points.is_empty() {
+            return Err(ERR_POINT_INSIDE);
+        }
+        let lines = chord
+            .points
+            .iter()
+            .map(|&(x, y)| Line::through_points(px, py, x, y))
+            .collect::<Result<Vec<Line>, &'static str>>()?;
+        Ok(TangentsFromPoint {
+            points: chord.points,
+            lines,
+            polar,
+        })
+    }
+
+    // y = kx + m 与曲线相切 ⇔ 联立方程的判别式关于m的方程有解
+    pub fn tangents_with_slope<C: Conic>(conic: &C, k: f64) -> Result<Vec<Line>, &'static str> {
+        let g = conic.general_form();
+        let qa = g.a + g.b * k + g.c * k * k;
+        if qa.abs() < EPSILON {
+            return Ok(Vec::new());
+        }
+        let u = g.b + 2.0 * g.c * k;
+        let v = g.d + g.e * k;
+        let ma = u * u - 4.0 * qa * g.c;
+        let mb = 2.0 * u * v - 4.0 * qa * g.e;
+        let mc = v * v - 4.0 * qa * g.f;
+        let intercepts = if ma.abs() < EPSILON {
+            if mb.abs() < EPSILON {
+                Vec::new()
+            } else {
+                vec![-mc / mb]
+            }
+        } else {
+            let disc = mb * mb - 4.0 * ma * mc;
+            if disc.abs() < EPSILON * (mb * mb).max((4.0 * ma * mc).abs()).max(EPSILON) {
+                vec![-mb / (2.0 * ma)]
+            } else if disc < 0.0 {
+                Vec::new()
+            } else {
+                vec![(-mb - disc.sqrt()) / (2.0 * ma), (-mb + disc.sqrt()) / (2.0 * ma)]
+            }
+        };
+        Ok(intercepts.into_iter().map(|m| Line::slope_intercept(k, m)).collect())
+    }
+
+    fn reflect_direction(dx: f64, dy: f64, mirror: &Line) -> (f64, f64) {
+        let norm = mirror.a.hypot(mirror.b);
+        let (nx, ny) = (mirror.a / norm, mirror.b / norm);
+        let dot = dx * nx + dy * ny;
+        (dx - 2.0 * dot * nx, dy - 2.0 * dot * ny)
+    }
+
+    fn same_direction(u: (f64, f64), v: (f64, f64)) -> bool {
+        let cross = u.0 * v.1 - u.1 * v.0;
+        let dot = u.0 * v.0 + u.1 * v.1;
+        cross.abs() < EPSILON * u.0.hypot(u.1) * v.0.hypot(v.1) && dot > 0.0
+    }
+
+    // 从一个焦点发出的光线经椭圆反射后经过另一个焦点
+    pub fn verify_reflection_ellipse(ellipse: &Ellipse, x0: f64, y0: f64) -> Result<bool, &'static str> {
+        let tangent = tangent_at(ellipse, x0, y0)?;
+        let [(f1x, f1y), (f2x, f2y)] = ellipse.foci();
+        let reflected = reflect_direction(x0 - f1x, y0 - f1y, &tangent);
+        Ok(same_direction(reflected, (f2x - x0, f2y - y0)))
+    }
+
+    // 从一个焦点发出的光线经双曲线反射后, 反向延长线经过另一个焦点
+    pub fn verify_reflection_hyperbola(hyperbola: &Hyperbola, x0: f64, y0: f64) -> Result<bool, &'static str> {
+        let tangent = tangent_at(hyperbola, x0, y0)?;
+        let [(f1x, f1y), (f2x, f2y)] = hyperbola.foci();
+        let (near, far) = if x0 > 0.0 { ((f2x, f2y), (f1x, f1y)) } else { ((f1x, f1y), (f2x, f2y)) };
+        let reflected = reflect_direction(x0 - near.0, y0 - near.1, &tangent);
+        Ok(same_direction(reflected, (x0 - far.0, y0 - far.1)))
+    }
+
+    // 平行于对称轴射入的光线经抛物线反射后经过焦点
+    pub fn verify_reflection_parabola(parabola: &Parabola, x0: f64, y0: f64) -> Result<bool, &'static str> {
+        let tangent = tangent_at(parabola, x0, y0)?;
+        let (fx, fy) = parabola.focus();
+        let reflected = reflect_direction(-1.0, 0.0, &tangent);
+        Ok(same_direction(reflected, (fx - x0, fy - y0)))
+    }
 }
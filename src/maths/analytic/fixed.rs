@@ -0,0 +1,142 @@
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+pub mod fixed_points {
+    use crate::maths::analytic::line::lines::Line;
+
+    const ERR_INSUFFICIENT_SAMPLES: &str = "有效采样数量必须至少为2";
+    const ERR_PARALLEL_FAMILY: &str = "直线族互相平行, 不存在公共定点";
+    const TOLERANCE: f64 = 1e-6;
+
+    // 采样结果: 最小二乘候选值、各样本残差, 以及采样时出错被跳过的参数个数
+    #[derive(Debug, Clone)]
+    pub struct FixedPointReport {
+        pub point: (f64, f64),
+        pub residuals: Vec<f64>,
+        pub max_residual: f64,
+        pub skipped: usize,
+        pub is_fixed: bool,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct ConstantReport {
+        pub value: f64,
+        pub residuals: Vec<f64>,
+        pub max_residual: f64,
+        pub skipped: usize,
+        pub is_constant: bool,
+    }
+
+    fn max_abs(values: &[f64]) -> f64 {
+        values.iter().fold(0.0, |m, &v| m.max(v.abs()))
+    }
+
+    // 最小化各直线到候选点距离的平方和
+    pub fn fixed_point_of_lines<F>(family: F, params: &[f64]) -> Result<FixedPointReport, &'static str>
+    where
+        F: Fn(f64) -> Result<Line, &'static str>,
+    {
+        let lines: Vec<Line> = params.iter().filter_map(|&t| family(t).ok()).collect();
+        if lines.len() < 2 {
+            return Err(ERR_INSUFFICIENT_SAMPLES);
+        }
+        let normalized: Vec<(f64, f64, f64)> = lines
+            .iter()
+            .map(|l| {
+                let n = l.a.hypot(l.b);
+                (l.a / n, l.b / n, l.c / n)
+            })
+            .collect();
+        let (mut saa, mut sab, mut sbb, mut sac, mut sbc) = (0.0, 0.0, 0.0, 0.0, 0.0);
+        for &(a, b, c) in &normalized {
+            saa += a * a;
+            sab += a * b;
+            sbb += b * b;
+            sac += a * c;
+            sbc += b * c;
+        }
+        let det = saa * sbb - sab * sab;
+        if det.abs() < TOLERANCE * TOLERANCE * normalized.len() as f64 {
+            return Err(ERR_PARALLEL_FAMILY);
+        }
+        let x = (-sac * sbb + sbc * sab) / det;
+        let y = (-sbc * saa + sac * sab) / det;
+        let residuals: Vec<f64> = normalized.iter().map(|&(a, b, c)| a * x + b * y + c).collect();
+        let max_residual = max_abs(&residuals);
+        Ok(FixedPointReport {
+            point: (x, y),
+            is_fixed: max_residual < TOLERANCE * (1.0 + x.hypot(y)),
+            residuals,
+            max_residual,
+            skipped: params.len() - lines.len(),
+        })
+    }
+
+    // 动弦 AB 所在直线是否过定点, family 返回弦的两个端点
+    pub fn fixed_point_of_chords<F>(family: F, params: &[f64]) -> Result<FixedPointReport, &'static str>
+    where
+        F: Fn(f64) -> Result<((f64, f64), (f64, f64)), &'static str>,
+    {
+        fixed_point_of_lines(
+            |t| {
+                let ((x1, y1), (x2, y2)) = family(t)?;
+                Line::through_points(x1, y1, x2, y2)
+            },
+            params,
+        )
+    }
+
+    pub fn fixed_point_of_points<F>(family: F, params: &[f64]) -> Result<FixedPointReport, &'static str>
+    where
+        F: Fn(f64) -> Result<(f64, f64), &'static str>,
+    {
+        let points: Vec<(f64, f64)> = params.iter().filter_map(|&t| family(t).ok()).collect();
+        if points.len() < 2 {
+            return Err(ERR_INSUFFICIENT_SAMPLES);
+        }
+        let n = points.len() as f64;
+        let x = points.iter().map(|p| p.0).sum::<f64>() / n;
+        let y = points.iter().map(|p| p.1).sum::<f64>() / n;
+        let residuals: Vec<f64> = points.iter().map(|p| (p.0 - x).hypot(p.1 - y)).collect();
+        let max_residual = max_abs(&residuals);
+        Ok(FixedPointReport {
+            point: (x, y),
+            is_fixed: max_residual < TOLERANCE * (1.0 + x.hypot(y)),
+            residuals,
+            max_residual,
+            skipped: params.len() - points.len(),
+        })
+    }
+
+    // 常数的最小二乘估计即样本均值
+    pub fn constant_value<F>(expression: F, params: &[f64]) -> Result<ConstantReport, &'static str>
+    where
+        F: Fn(f64) -> Result<f64, &'static str>,
+    {
+        let values: Vec<f64> = params.iter().filter_map(|&t| expression(t).ok()).collect();
+        if values.len() < 2 {
+            return Err(ERR_INSUFFICIENT_SAMPLES);
+        }
+        let value = values.iter().sum::<f64>() / values.len() as f64;
+        let residuals: Vec<f64> = values.iter().map(|v| v - value).collect();
+        let max_residual = max_abs(&residuals);
+        Ok(ConstantReport {
+            value,
+            is_constant: max_residual < TOLERANCE * value.abs().max(1.0),
+            residuals,
+            max_residual,
+            skipped: params.len() - values.len(),
+        })
+    }
+
+    pub fn sample_params(t0: f64, t1: f64, count: usize) -> Vec<f64> {
+        if count < 2 {
+            return vec![t0];
+        }
+        let step = (t1 - t0) / (count - 1) as f64;
+        (0..count).map(|i| t0 + step * i as f64).collect()
+    }
+}
@@ -8,6 +8,76 @@ pub mod lines {
     const ERR_COINCIDENT_POINTS: &str = "两点不能重合";
     const ERR_VERTICAL_LINE: &str = "垂直直线斜率不存在";
     const ERR_INVALID_LINE_PARAMS: &str = "直线参数A和B不能同时为0";
+    // 直线与极坐标模块共用的浮点容差
+    pub const EPSILON: f64 = 1e-10;
+
+    // 一般式 Ax + By + C = 0
+    #[derive(Debug, Clone, Copy)]
+    pub struct Line {
+        pub a: f64,
+        pub b: f64,
+        pub c: f64,
+    }
+
+    impl Line {
+        pub fn new(a: f64, b: f64, c: f64) -> Result<Line, &'static str> {
+            if a == 0.0 && b == 0.0 {
+                return Err(ERR_INVALID_LINE_PARAMS);
+            }
+            Ok(Line { a, b, c })
+        }
+
+        pub fn slope_intercept(k: f64, m: f64) -> Line {
+            Line { a: k, b: -1.0, c: m }
+        }
+
+        pub fn point_slope(x0: f64, y0: f64, k: f64) -> Line {
+            Line { a: k, b: -1.0, c: y0 - k * x0 }
+        }
+
+        pub fn vertical(x0: f64) -> Line {
+            Line { a: 1.0, b: 0.0, c: -x0 }
+        }
+
+        pub fn through_points(x1: f64, y1: f64, x2: f64, y2: f64) -> Result<Line, &'static str> {
+            if x1 == x2 && y1 == y2 {
+                return Err(ERR_COINCIDENT_POINTS);
+            }
+            Ok(Line {
+                a: y2 - y1,
+                b: x1 - x2,
+                c: x2 * y1 - x1 * y2,
+            })
+        }
+
+        pub fn is_vertical(&self) -> bool {
+            self.b.abs() < EPSILON * self.a.abs().max(1.0)
+        }
+
+        pub fn slope(&self) -> Result<f64, &'static str> {
+            if self.is_vertical() {
+                return Err(ERR_VERTICAL_LINE);
+            }
+            Ok(-self.a / self.b)
+        }
+
+        pub fn y_intercept(&self) -> Result<f64, &'static str> {
+            if self.is_vertical() {
+                return Err(ERR_VERTICAL_LINE);
+            }
+            Ok(-self.c / self.b)
+        }
+
+        // 舍入误差随坐标和常数项的量级增长, 容差按其放缩
+        pub fn contains(&self, x: f64, y: f64) -> bool {
+            let scale = x.abs().max(y.abs()).max(self.c.abs() / self.a.hypot(self.b)).max(1.0);
+            point_to_line_distance(x, y, self.a, self.b, self.c).is_ok_and(|d| d < EPSILON * scale)
+        }
+
+        pub fn distance_to(&self, x0: f64, y0: f64) -> Result<f64, &'static str> {
+            point_to_line_distance(x0, y0, self.a, self.b, self.c)
+        }
+    }
 
     pub fn slope(x1: f64, y1: f64, x2: f64, y2: f64) -> Result<f64, &'static str> {
         if x1 == x2 && y1 == y2 {
@@ -0,0 +1,227 @@
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+pub mod polar_coordinates {
+    use crate::maths::analytic::conic::conic_sections::{CanonicalForm, Conic, ConicKind, Ellipse, Hyperbola, Parabola};
+    use crate::maths::analytic::line::lines::{EPSILON, Line};
+    use std::f64::consts::TAU;
+
+    const ERR_INVALID_RADIUS: &str = "圆的半径需大于0";
+    const ERR_INVALID_ECCENTRICITY: &str = "离心率需大于0";
+    const ERR_INVALID_FOCAL_PARAM: &str = "焦准距p需大于0";
+    const ERR_UNDEFINED_RHO: &str = "该极角方向与曲线无交点";
+    const ERR_ZERO_DIRECTION: &str = "方向向量不能为零向量";
+    const ERR_NO_INTERSECTION: &str = "直线与曲线没有两个交点";
+
+    fn normalize_angle(theta: f64) -> f64 {
+        let t = theta.rem_euclid(TAU);
+        if t >= TAU { 0.0 } else { t }
+    }
+
+    pub fn cartesian_to_polar(x: f64, y: f64) -> (f64, f64) {
+        let rho = x.hypot(y);
+        if rho == 0.0 {
+            return (0.0, 0.0);
+        }
+        (rho, normalize_angle(y.atan2(x)))
+    }
+
+    pub fn polar_to_cartesian(rho: f64, theta: f64) -> (f64, f64) {
+        (rho * theta.cos(), rho * theta.sin())
+    }
+
+    // ρcos(θ - α) = d, d ≥ 0
+    #[derive(Debug, Clone, Copy)]
+    pub struct PolarLine {
+        pub d: f64,
+        pub alpha: f64,
+    }
+
+    impl PolarLine {
+        pub fn rho(&self, theta: f64) -> Result<f64, &'static str> {
+            let cos = (theta - self.alpha).cos();
+            if cos.abs() < EPSILON {
+                return Err(ERR_UNDEFINED_RHO);
+            }
+            Ok(self.d / cos)
+        }
+    }
+
+    pub fn line_to_polar(line: &Line) -> PolarLine {
+        let n = line.a.hypot(line.b);
+        let sign = if line.c > 0.0 { -1.0 } else { 1.0 };
+        let (a, b) = (sign * line.a / n, sign * line.b / n);
+        PolarLine {
+            d: (-sign * line.c / n).abs(),
+            alpha: normalize_angle(b.atan2(a)),
+        }
+    }
+
+    pub fn polar_to_line(line: &PolarLine) -> Line {
+        Line {
+            a: line.alpha.cos(),
+            b: line.alpha.sin(),
+            c: -line.d,
+        }
+    }
+
+    // ρ² - 2ρρ₀cos(θ - θ₀) + ρ₀² - r² = 0
+    #[derive(Debug, Clone, Copy)]
+    pub struct PolarCircle {
+        pub center_rho: f64,
+        pub center_theta: f64,
+        pub r: f64,
+    }
+
+    impl PolarCircle {
+        pub fn rho(&self, theta: f64) -> Vec<f64> {
+            let b = self.center_rho * (theta - self.center_theta).cos();
+            let disc = b * b - self.center_rho * self.center_rho + self.r * self.r;
+            if disc < -EPSILON {
+                return Vec::new();
+            }
+            let root = disc.max(0.0).sqrt();
+            let mut roots: Vec<f64> = [b + root, b - root].into_iter().filter(|&r| r >= 0.0).collect();
+            roots.dedup_by(|x, y| (*x - *y).abs() < EPSILON);
+            roots
+        }
+
+        pub fn passes_through_pole(&self) -> bool {
+            (self.center_rho - self.r).abs() < EPSILON * self.r.max(1.0)
+        }
+    }
+
+    pub fn circle_to_polar(x0: f64, y0: f64, r: f64) -> Result<PolarCircle, &'static str> {
+        if r <= 0.0 {
+            return Err(ERR_INVALID_RADIUS);
+        }
+        let (center_rho, center_theta) = cartesian_to_polar(x0, y0);
+        Ok(PolarCircle { center_rho, center_theta, r })
+    }
+
+    pub fn polar_to_circle(circle: &PolarCircle) -> (f64, f64, f64) {
+        let (x0, y0) = polar_to_cartesian(circle.center_rho, circle.center_theta);
+        (x0, y0, circle.r)
+    }
+
+    // 以焦点为极点: ρ = ep / (1 - e·cosθ), p 为焦点到对应准线的距离
+    #[derive(Debug, Clone, Copy)]
+    pub struct PolarConic {
+        pub e: f64,
+        pub p: f64,
+    }
+
+    impl PolarConic {
+        pub fn new(e: f64, p: f64) -> Result<PolarConic, &'static str> {
+            if e <= 0.0 {
+                return Err(ERR_INVALID_ECCENTRICITY);
+            }
+            if p <= 0.0 {
+                return Err(ERR_INVALID_FOCAL_PARAM);
+            }
+            Ok(PolarConic { e, p })
+        }
+
+        pub fn rho(&self, theta: f64) -> Result<f64, &'static str> {
+            let denominator = 1.0 - self.e * theta.cos();
+            if denominator.abs() < EPSILON {
+                return Err(ERR_UNDEFINED_RHO);
+            }
+            Ok(self.e * self.p / denominator)
+        }
+
+        // 过极点(焦点)且倾斜角为θ的焦点弦长 2ep / |1 - e²cos²θ|
+        pub fn focal_chord(&self, theta: f64) -> Result<f64, &'static str> {
+            let denominator = 1.0 - (self.e * theta.cos()).powi(2);
+            if denominator.abs() < EPSILON {
+                return Err(ERR_UNDEFINED_RHO);
+            }
+            Ok((2.0 * self.e * self.p / denominator).abs())
+        }
+
+        pub fn kind(&self) -> ConicKind {
+            if (self.e - 1.0).abs() < EPSILON {
+                ConicKind::Parabola
+            } else if self.e < 1.0 {
+                ConicKind::Ellipse
+            } else {
+                ConicKind::Hyperbola
+            }
+        }
+
+        pub fn to_canonical(&self) -> CanonicalForm {
+            let ep = self.e * self.p;
+            match self.kind() {
+                ConicKind::Parabola => CanonicalForm::Parabola(Parabola { p: self.p }),
+                ConicKind::Ellipse => {
+                    let a = ep / (1.0 - self.e * self.e);
+                    CanonicalForm::Ellipse(Ellipse { a, b: a * (1.0 - self.e * self.e).sqrt() })
+                }
+                _ => {
+                    let a = ep / (self.e * self.e - 1.0);
+                    CanonicalForm::Hyperbola(Hyperbola { a, b: a * (self.e * self.e - 1.0).sqrt() })
+                }
+            }
+        }
+    }
+
+    // 极点取左焦点, 与 focal_radius_ellipse 一致
+    pub fn ellipse_to_polar(ellipse: &Ellipse) -> PolarConic {
+        let c = ellipse.focal_distance();
+        PolarConic {
+            e: c / ellipse.a,
+            p: ellipse.b * ellipse.b / c,
+        }
+    }
+
+    // 极点取右焦点, ρ > 0 对应右支
+    pub fn hyperbola_to_polar(hyperbola: &Hyperbola) -> PolarConic {
+        let c = hyperbola.focal_distance();
+        PolarConic {
+            e: c / hyperbola.a,
+            p: hyperbola.b * hyperbola.b / c,
+        }
+    }
+
+    pub fn parabola_to_polar(parabola: &Parabola) -> PolarConic {
+        PolarConic { e: 1.0, p: parabola.p }
+    }
+
+    // 直线 x = x0 + at, y = y0 + bt 与曲线联立, t₁, t₂ 为两交点对应参数
+    #[derive(Debug, Clone, Copy)]
+    pub struct ParametricChord {
+        pub t_sum: f64,
+        pub t_product: f64,
+        pub chord_length: f64,
+        pub distance_product: f64,
+        pub distance_sum: f64,
+    }
+
+    pub fn parametric_chord<C: Conic>(conic: &C, x0: f64, y0: f64, a: f64, b: f64) -> Result<ParametricChord, &'static str> {
+        let norm = a.hypot(b);
+        if norm < EPSILON {
+            return Err(ERR_ZERO_DIRECTION);
+        }
+        let g = conic.general_form();
+        let qa = g.a * a * a + g.b * a * b + g.c * b * b;
+        let qb = 2.0 * g.a * x0 * a + g.b * (x0 * b + y0 * a) + 2.0 * g.c * y0 * b + g.d * a + g.e * b;
+        let qc = g.value(x0, y0);
+        let discriminant = qb * qb - 4.0 * qa * qc;
+        if qa.abs() < EPSILON || discriminant <= 0.0 {
+            return Err(ERR_NO_INTERSECTION);
+        }
+        let t_sum = -qb / qa;
+        let t_product = qc / qa;
+        let gap = discriminant.sqrt() / qa.abs();
+        Ok(ParametricChord {
+            t_sum,
+            t_product,
+            chord_length: gap * norm,
+            distance_product: t_product.abs() * norm * norm,
+            distance_sum: if t_product < 0.0 { gap * norm } else { t_sum.abs() * norm },
+        })
+    }
+}
@@ -8,7 +8,6 @@ pub mod derivatives {
     const ERR_DIVISION_BY_ZERO: &str = "除数不能为零";
     const ERR_NEGATIVE_BASE: &str = "对数的底数必须为正数且不等于1";
     const ERR_LOGARITHM_DOMAIN: &str = "对数的真数必须为正数";
-    const ERR_SQUARE_ROOT_DOMAIN: &str = "开平方的数必须为非负数";
 
     pub fn constant_deriv(_c: f64) -> f64 {
         0.0
@@ -9,8 +9,8 @@ pub mod analysis {
     const ERR_EQUAL_VALUES: &str = "两个值不能相等";
     const ERR_EMPTY_POINTS: &str = "点集不能为空";
     const ERR_INVALID_LHOSPITAL: &str = "不满足洛必达法则适用条件";
-    const ERR_NEGATIVE_ORDER: &str = "展开阶数不能为负数";
     const ERR_DERIVATIVE_ZERO: &str = "导数不能为零";
+    const ERR_JENSEN_VIOLATED: &str = "函数值不满足所给凹凸性对应的琴生不等式";
 
     pub fn logarithmic_mean_inequality(a: f64, b: f64) -> Result<(f64, f64, f64), &'static str> {
         if a <= 0.0 || b <= 0.0 {
@@ -36,6 +36,12 @@ pub mod analysis {
         let mean = points.iter().sum::<f64>() / n;
         let f_mean = f(mean);
         let mean_f = points.iter().map(|&x| f(x)).sum::<f64>() / n;
+        // 凸函数 f(均值) <= 均值f, 凹函数反之, 容差随数值大小放缩
+        let tolerance = 1e-9 * f_mean.abs().max(mean_f.abs()).max(1.0);
+        let holds = if is_convex { f_mean <= mean_f + tolerance } else { f_mean >= mean_f - tolerance };
+        if !holds {
+            return Err(ERR_JENSEN_VIOLATED);
+        }
         Ok((f_mean, mean_f))
     }
 
@@ -74,9 +80,6 @@ pub mod analysis {
         if n > derivatives.len() {
             return Err("导数数量不足");
         }
-        if n < 0 {
-            return Err(ERR_NEGATIVE_ORDER);
-        }
 
         let mut result = f(x0);
         let dx = x - x0;
@@ -0,0 +1,401 @@
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+pub mod limits {
+    use crate::maths::algebra::sequence::sequences::{RecurrenceSequence, Sequence};
+
+    const ERR_INSUFFICIENT_TERMS: &str = "至少需要8项才能估计极限";
+    const ERR_NOT_FINITE: &str = "数列中出现了非数值项";
+    const ERR_INVALID_INTERVAL: &str = "区间需满足lo < hi";
+    const ERR_INVALID_SAMPLES: &str = "采样点数量必须至少为2";
+    const ERR_ZERO_TERM: &str = "级数中出现为0的项, 比值判别法不适用";
+    const ERR_NON_POSITIVE_REFERENCE: &str = "比较级数的各项必须为正";
+
+    const MIN_TERMS: usize = 8;
+    const CONVERGENCE_TOLERANCE: f64 = 1e-6;
+    const DIVERGENCE_LIMIT: f64 = 1e12;
+    const GROWTH_FACTOR: f64 = 1.1;
+    const SUMMABLE_DECAY: f64 = 0.45;
+    const GEOMETRIC_SPREAD: f64 = 0.1;
+    const AITKEN_ROUNDS: usize = 3;
+    const RICHARDSON_LEVELS: u32 = 3;
+    const RICHARDSON_MIN_BASE: usize = 4;
+    const NEUTRAL_TOLERANCE: f64 = 1e-6;
+    const BISECTION_STEPS: usize = 200;
+
+    // 数值判定的结果, 并非严格证明
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum LimitBehavior {
+        Converges { limit: f64, error: f64 },
+        DivergesToInfinity,
+        DivergesToNegativeInfinity,
+        // 下极限与上极限的估计, 无界时为 ±∞
+        Oscillates { lower: f64, upper: f64 },
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Stability {
+        Attracting,
+        Repelling,
+        Neutral,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct FixedPoint {
+        pub x: f64,
+        pub derivative: f64,
+        pub stability: Stability,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct IterationAnalysis {
+        pub orbit: LimitBehavior,
+        pub fixed_points: Vec<FixedPoint>,
+        pub attracted_to: Option<FixedPoint>,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum SeriesVerdict {
+        Converges,
+        Diverges,
+        Inconclusive,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct SeriesTest {
+        pub statistic: f64,
+        pub verdict: SeriesVerdict,
+    }
+
+    // Aitken Δ² 加速: x_n - (Δx_n)² / Δ²x_n
+    pub fn aitken(values: &[f64]) -> Vec<f64> {
+        values
+            .windows(3)
+            .map(|w| {
+                let d2 = w[2] - 2.0 * w[1] + w[0];
+                if d2 == 0.0 {
+                    w[2]
+                } else {
+                    w[0] - (w[1] - w[0]).powi(2) / d2
+                }
+            })
+            .collect()
+    }
+
+    // 误差按 1/n 的幂展开时, 用第 n, 2n, 4n, ... 项逐级外推
+    pub fn richardson<S: Sequence + ?Sized>(sequence: &S, n: usize, levels: u32) -> Result<f64, &'static str> {
+        let values = (0..=levels).map(|i| sequence.term(n << i)).collect::<Result<Vec<f64>, &'static str>>()?;
+        Ok(richardson_table(&values).0)
+    }
+
+    // 返回外推值及最后两级的差作为误差估计
+    fn richardson_table(values: &[f64]) -> (f64, f64) {
+        let mut row = values.to_vec();
+        let mut previous = row[row.len() - 1];
+        let mut factor = 1.0;
+        while row.len() > 1 {
+            factor *= 2.0;
+            previous = row[row.len() - 1];
+            row = row.windows(2).map(|w| (factor * w[1] - w[0]) / (factor - 1.0)).collect();
+        }
+        (row[0], (row[0] - previous).abs())
+    }
+
+    // 在原数列、Aitken 加速和 Richardson 外推中取误差估计最小者
+    fn accelerate(values: &[f64]) -> (f64, f64) {
+        let n = values.len();
+        let mut best = (values[n - 1], (values[n - 1] - values[n - 2]).abs());
+
+        let mut row = values.to_vec();
+        for _ in 0..AITKEN_ROUNDS {
+            if row.len() < 5 {
+                break;
+            }
+            row = aitken(&row);
+            let m = row.len();
+            let error = (row[m - 1] - row[m - 2]).abs();
+            if row[m - 1].is_finite() && error < best.1 {
+                best = (row[m - 1], error);
+            }
+        }
+
+        let base = n >> RICHARDSON_LEVELS;
+        if base >= RICHARDSON_MIN_BASE {
+            let samples: Vec<f64> = (0..=RICHARDSON_LEVELS).map(|i| values[(base << i) - 1]).collect();
+            let (limit, error) = richardson_table(&samples);
+            if limit.is_finite() && error < best.1 {
+                best = (limit, error);
+            }
+        }
+        best
+    }
+
+    fn converged(estimate: (f64, f64)) -> bool {
+        estimate.1 <= CONVERGENCE_TOLERANCE * estimate.0.abs().max(1.0)
+    }
+
+    fn max_abs(values: &[f64]) -> f64 {
+        values.iter().fold(0.0, |m, v| m.max(v.abs()))
+    }
+
+    // 相邻增量之比近似为常数 r < 1 时增量按几何级数衰减, 数列必收敛;
+    // p 级数型增量之比为 1 - p/n, 其变化幅度与 1 - r 同阶, 不会被误判
+    fn geometric_decay(differences: &[f64]) -> bool {
+        let ratios: Vec<f64> = differences.windows(2).map(|w| w[1] / w[0]).collect();
+        if ratios.is_empty() {
+            return false;
+        }
+        let low = ratios.iter().cloned().fold(f64::INFINITY, f64::min);
+        let high = ratios.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        high < 1.0 && high - low <= GEOMETRIC_SPREAD * (1.0 - high)
+    }
+
+    // 尾部单调时先看增量是否几何衰减, 否则按 p 级数判断:
+    // 增量 ~ n^(-p), 由 n/2 到 n 衰减比为 2^(-p), p > 1 才可求和
+    fn monotone_behavior(values: &[f64]) -> Option<LimitBehavior> {
+        let tail = &values[values.len() / 2..];
+        let differences: Vec<f64> = tail.windows(2).map(|w| w[1] - w[0]).collect();
+        let rising = differences.iter().all(|&d| d > 0.0);
+        let falling = differences.iter().all(|&d| d < 0.0);
+        if !(rising || falling) {
+            return None;
+        }
+        let last = values[values.len() - 1];
+        let decay = (differences[differences.len() - 1] / differences[0]).abs();
+        let summable = geometric_decay(&differences) || decay < SUMMABLE_DECAY;
+        if last.abs() > DIVERGENCE_LIMIT || !summable {
+            return Some(if rising { LimitBehavior::DivergesToInfinity } else { LimitBehavior::DivergesToNegativeInfinity });
+        }
+        let (limit, error) = accelerate(values);
+        Some(LimitBehavior::Converges { limit, error })
+    }
+
+    fn subsequence_limit(values: &[f64]) -> Option<(f64, f64)> {
+        if let Some(behavior) = monotone_behavior(values).filter(|_| values.len() >= MIN_TERMS) {
+            return match behavior {
+                LimitBehavior::Converges { limit, error } => Some((limit, error)),
+                _ => None,
+            };
+        }
+        let estimate = accelerate(values);
+        converged(estimate).then_some(estimate)
+    }
+
+    pub fn estimate_limit_from_terms(values: &[f64]) -> Result<LimitBehavior, &'static str> {
+        let n = values.len();
+        if n < MIN_TERMS {
+            return Err(ERR_INSUFFICIENT_TERMS);
+        }
+        if values.iter().any(|v| v.is_nan()) {
+            return Err(ERR_NOT_FINITE);
+        }
+        let last = values[n - 1];
+        if last.is_infinite() {
+            return Ok(if last > 0.0 { LimitBehavior::DivergesToInfinity } else { LimitBehavior::DivergesToNegativeInfinity });
+        }
+        if let Some(behavior) = monotone_behavior(values) {
+            return Ok(behavior);
+        }
+
+        // 非单调且振幅仍在增大
+        let min = |v: &[f64]| v.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = |v: &[f64]| v.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let quarter = n / 4;
+        let (third, fourth) = (&values[n - 2 * quarter..n - quarter], &values[n - quarter..]);
+        if max_abs(fourth) > GROWTH_FACTOR * max_abs(third) {
+            let lower = if min(fourth) < 0.0 && min(fourth) < GROWTH_FACTOR * min(third) { f64::NEG_INFINITY } else { min(fourth) };
+            let upper = if max(fourth) > 0.0 && max(fourth) > GROWTH_FACTOR * max(third) { f64::INFINITY } else { max(fourth) };
+            return Ok(LimitBehavior::Oscillates { lower, upper });
+        }
+
+        // 奇偶子列分别求极限, 二者不同即为振荡
+        let odd: Vec<f64> = values.iter().step_by(2).copied().collect();
+        let even: Vec<f64> = values.iter().skip(1).step_by(2).copied().collect();
+        if let (Some(odd), Some(even)) = (subsequence_limit(&odd), subsequence_limit(&even)) {
+            let gap = (odd.0 - even.0).abs();
+            let error = odd.1.max(even.1);
+            if gap > error + CONVERGENCE_TOLERANCE * odd.0.abs().max(even.0.abs()).max(1.0) {
+                return Ok(LimitBehavior::Oscillates { lower: odd.0.min(even.0), upper: odd.0.max(even.0) });
+            }
+            return Ok(LimitBehavior::Converges { limit: (odd.0 + even.0) / 2.0, error: gap.max(error) });
+        }
+        let whole = accelerate(values);
+        if converged(whole) {
+            return Ok(LimitBehavior::Converges { limit: whole.0, error: whole.1 });
+        }
+        let tail = &values[n / 2..];
+        Ok(LimitBehavior::Oscillates { lower: min(tail), upper: max(tail) })
+    }
+
+    pub fn estimate_limit<S: Sequence + ?Sized>(sequence: &S, max_terms: usize) -> Result<LimitBehavior, &'static str> {
+        let values: Vec<f64> = sequence.terms().take(max_terms).collect();
+        estimate_limit_from_terms(&values)
+    }
+
+    // 线性递推 a_n = Σ coeffs[j]·a_(n-1-j) 的极限
+    pub fn recurrence_limit(initial: &[f64], coeffs: &[f64], max_terms: usize) -> Result<LimitBehavior, &'static str> {
+        estimate_limit(&RecurrenceSequence::new(initial, coeffs)?, max_terms)
+    }
+
+    fn derivative<F: Fn(f64) -> f64>(f: &F, x: f64) -> f64 {
+        let h = 1e-4 * x.abs().max(1.0);
+        let d1 = (f(x + h) - f(x - h)) / (2.0 * h);
+        let d2 = (f(x + h / 2.0) - f(x - h / 2.0)) / h;
+        (4.0 * d2 - d1) / 3.0
+    }
+
+    fn classify<F: Fn(f64) -> f64>(f: &F, x: f64) -> FixedPoint {
+        let derivative = derivative(f, x);
+        let stability = if (derivative.abs() - 1.0).abs() < NEUTRAL_TOLERANCE {
+            Stability::Neutral
+        } else if derivative.abs() < 1.0 {
+            Stability::Attracting
+        } else {
+            Stability::Repelling
+        };
+        FixedPoint { x, derivative, stability }
+    }
+
+    // 在 [lo, hi] 上等距采样 f(x) - x, 变号区间内二分求不动点, |f'| 判断稳定性
+    pub fn fixed_points<F: Fn(f64) -> f64>(f: &F, lo: f64, hi: f64, samples: usize) -> Result<Vec<FixedPoint>, &'static str> {
+        if lo >= hi {
+            return Err(ERR_INVALID_INTERVAL);
+        }
+        if samples < 2 {
+            return Err(ERR_INVALID_SAMPLES);
+        }
+        let g = |x: f64| f(x) - x;
+        let step = (hi - lo) / (samples - 1) as f64;
+        let mut roots: Vec<f64> = Vec::new();
+        for i in 0..samples - 1 {
+            let (mut a, mut b) = (lo + step * i as f64, lo + step * (i + 1) as f64);
+            let (mut ga, gb) = (g(a), g(b));
+            if ga == 0.0 {
+                roots.push(a);
+                continue;
+            }
+            if i == samples - 2 && gb == 0.0 {
+                roots.push(b);
+            }
+            if ga * gb >= 0.0 {
+                continue;
+            }
+            for _ in 0..BISECTION_STEPS {
+                let m = (a + b) / 2.0;
+                let gm = g(m);
+                if gm == 0.0 || m == a || m == b {
+                    a = m;
+                    b = m;
+                    break;
+                }
+                if ga * gm < 0.0 {
+                    b = m;
+                } else {
+                    a = m;
+                    ga = gm;
+                }
+            }
+            let root = (a + b) / 2.0;
+            roots.push(if root.abs() < f64::EPSILON * (hi - lo) { 0.0 } else { root });
+        }
+        Ok(roots.into_iter().map(|x| classify(f, x)).collect())
+    }
+
+    // 迭代 a_(n+1) = f(a_n), 并与 [lo, hi] 内的不动点对照
+    pub fn iteration_analysis<F: Fn(f64) -> f64>(f: &F, a1: f64, lo: f64, hi: f64, max_terms: usize) -> Result<IterationAnalysis, &'static str> {
+        let orbit_terms: Vec<f64> = std::iter::successors(Some(a1), |&a| Some(f(a))).take(max_terms).collect();
+        let orbit = estimate_limit_from_terms(&orbit_terms)?;
+        let fixed_points = fixed_points(f, lo, hi, max_terms.max(MIN_TERMS))?;
+        let attracted_to = match orbit {
+            LimitBehavior::Converges { limit, .. } => {
+                let tolerance = CONVERGENCE_TOLERANCE.sqrt() * limit.abs().max(1.0);
+                fixed_points.iter().find(|p| (p.x - limit).abs() < tolerance).copied().or_else(|| Some(classify(f, limit)))
+            }
+            _ => None,
+        };
+        Ok(IterationAnalysis { orbit, fixed_points, attracted_to })
+    }
+
+    fn verdict_from_statistic(statistic: LimitBehavior) -> SeriesTest {
+        match statistic {
+            LimitBehavior::Converges { limit, error } => {
+                let margin = error.max(CONVERGENCE_TOLERANCE);
+                let verdict = if limit < 1.0 - margin {
+                    SeriesVerdict::Converges
+                } else if limit > 1.0 + margin {
+                    SeriesVerdict::Diverges
+                } else {
+                    SeriesVerdict::Inconclusive
+                };
+                SeriesTest { statistic: limit, verdict }
+            }
+            LimitBehavior::DivergesToInfinity => SeriesTest { statistic: f64::INFINITY, verdict: SeriesVerdict::Diverges },
+            LimitBehavior::Oscillates { upper, .. } if upper < 1.0 => SeriesTest { statistic: upper, verdict: SeriesVerdict::Converges },
+            LimitBehavior::Oscillates { lower, .. } if lower > 1.0 => SeriesTest { statistic: lower, verdict: SeriesVerdict::Diverges },
+            _ => SeriesTest { statistic: f64::NAN, verdict: SeriesVerdict::Inconclusive },
+        }
+    }
+
+    // 比值判别法: lim |a_(n+1) / a_n| = ρ, ρ < 1 收敛, ρ > 1 发散
+    pub fn ratio_test<S: Sequence + ?Sized>(series: &S, max_terms: usize) -> Result<SeriesTest, &'static str> {
+        let values: Vec<f64> = series.terms().take(max_terms + 1).collect();
+        if values.contains(&0.0) {
+            return Err(ERR_ZERO_TERM);
+        }
+        let ratios: Vec<f64> = values.windows(2).map(|w| (w[1] / w[0]).abs()).collect();
+        Ok(verdict_from_statistic(estimate_limit_from_terms(&ratios)?))
+    }
+
+    // 根值判别法: lim |a_n|^(1/n)
+    pub fn root_test<S: Sequence + ?Sized>(series: &S, max_terms: usize) -> Result<SeriesTest, &'static str> {
+        let roots: Vec<f64> = series
+            .terms()
+            .take(max_terms)
+            .enumerate()
+            .map(|(i, a)| a.abs().powf(1.0 / (i + 1) as f64))
+            .collect();
+        Ok(verdict_from_statistic(estimate_limit_from_terms(&roots)?))
+    }
+
+    // 先用极限形式 lim a_n / b_n = c 判断, 再退回逐项比较 |a_n| <= b_n 或 a_n >= b_n
+    pub fn comparison_test<S, T>(series: &S, reference: &T, reference_converges: bool, max_terms: usize) -> Result<SeriesTest, &'static str>
+    where
+        S: Sequence + ?Sized,
+        T: Sequence + ?Sized,
+    {
+        let a: Vec<f64> = series.terms().take(max_terms).collect();
+        let b: Vec<f64> = reference.terms().take(max_terms).collect();
+        if b.len() < a.len() || b.iter().any(|&x| x <= 0.0) {
+            return Err(ERR_NON_POSITIVE_REFERENCE);
+        }
+        let ratios: Vec<f64> = a.iter().zip(&b).map(|(x, y)| x.abs() / y).collect();
+        let statistic = estimate_limit_from_terms(&ratios)?;
+        let verdict = if reference_converges { SeriesVerdict::Converges } else { SeriesVerdict::Diverges };
+        let c = match statistic {
+            LimitBehavior::Converges { limit, error } => {
+                let tolerance = error.max(CONVERGENCE_TOLERANCE);
+                if limit > tolerance || (limit.abs() <= tolerance && reference_converges) {
+                    return Ok(SeriesTest { statistic: limit, verdict });
+                }
+                limit
+            }
+            LimitBehavior::DivergesToInfinity if !reference_converges => {
+                return Ok(SeriesTest { statistic: f64::INFINITY, verdict });
+            }
+            LimitBehavior::DivergesToInfinity => f64::INFINITY,
+            _ => f64::NAN,
+        };
+        let tail = a.len() / 2;
+        let dominated = a.iter().zip(&b).skip(tail).all(|(x, y)| x.abs() <= *y);
+        let dominates = a.iter().zip(&b).skip(tail).all(|(x, y)| x >= y);
+        let verdict = match (reference_converges, dominated, dominates) {
+            (true, true, _) => SeriesVerdict::Converges,
+            (false, _, true) => SeriesVerdict::Diverges,
+            _ => SeriesVerdict::Inconclusive,
+        };
+        Ok(SeriesTest { statistic: c, verdict })
+    }
+}
@@ -0,0 +1,244 @@
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+pub mod parametric_curves {
+    use crate::maths::analytic::line::lines::Line;
+
+    const ERR_SINGULARITY: &str = "参数处于曲线的奇点附近, 无法求值";
+    const ERR_STATIONARY: &str = "该点速度为零, 切线方向不确定";
+    const ERR_INVALID_INTERVAL: &str = "参数区间需满足t0 <= t1";
+    const ERR_NOT_CONVERGED: &str = "数值计算未收敛";
+    const ERR_INVALID_SAMPLES: &str = "采样点数量必须至少为2";
+
+    const STEP: f64 = 1e-3;
+    const SINGULAR_LIMIT: f64 = 1e10;
+    const EPSILON: f64 = 1e-10;
+    const SIMPSON_TOLERANCE: f64 = 1e-10;
+    const SIMPSON_DEPTH: u32 = 40;
+    // 黄金分割每步区间缩为 0.618 倍, 200 步足以覆盖 f64 的全部精度
+    const GOLDEN_SECTION_STEPS: usize = 200;
+
+    #[derive(Debug, Clone, Copy)]
+    pub struct ClosestPoint {
+        pub t: f64,
+        pub point: (f64, f64),
+        pub distance: f64,
+    }
+
+    fn evaluate<F>(curve: &F, t: f64) -> Result<(f64, f64), &'static str>
+    where
+        F: Fn(f64) -> Result<(f64, f64), &'static str>,
+    {
+        let (x, y) = curve(t).map_err(|_| ERR_SINGULARITY)?;
+        if !x.is_finite() || !y.is_finite() || x.abs() > SINGULAR_LIMIT || y.abs() > SINGULAR_LIMIT {
+            return Err(ERR_SINGULARITY);
+        }
+        Ok((x, y))
+    }
+
+    fn central_difference<F>(curve: &F, t: f64, h: f64) -> Result<(f64, f64), &'static str>
+    where
+        F: Fn(f64) -> Result<(f64, f64), &'static str>,
+    {
+        let (x1, y1) = evaluate(curve, t + h)?;
+        let (x0, y0) = evaluate(curve, t - h)?;
+        Ok(((x1 - x0) / (2.0 * h), (y1 - y0) / (2.0 * h)))
+    }
+
+    fn second_difference<F>(curve: &F, t: f64, h: f64) -> Result<(f64, f64), &'static str>
+    where
+        F: Fn(f64) -> Result<(f64, f64), &'static str>,
+    {
+        let (x1, y1) = evaluate(curve, t + h)?;
+        let (x, y) = evaluate(curve, t)?;
+        let (x0, y0) = evaluate(curve, t - h)?;
+        Ok(((x1 - 2.0 * x + x0) / (h * h), (y1 - 2.0 * y + y0) / (h * h)))
+    }
+
+    // 步长减半后做一次 Richardson 外推, 误差降到 O(h⁴)
+    pub fn velocity<F>(curve: &F, t: f64) -> Result<(f64, f64), &'static str>
+    where
+        F: Fn(f64) -> Result<(f64, f64), &'static str>,
+    {
+        let (x1, y1) = central_difference(curve, t, STEP)?;
+        let (x2, y2) = central_difference(curve, t, STEP / 2.0)?;
+        Ok(((4.0 * x2 - x1) / 3.0, (4.0 * y2 - y1) / 3.0))
+    }
+
+    pub fn acceleration<F>(curve: &F, t: f64) -> Result<(f64, f64), &'static str>
+    where
+        F: Fn(f64) -> Result<(f64, f64), &'static str>,
+    {
+        let (x1, y1) = second_difference(curve, t, STEP * 10.0)?;
+        let (x2, y2) = second_difference(curve, t, STEP * 5.0)?;
+        Ok(((4.0 * x2 - x1) / 3.0, (4.0 * y2 - y1) / 3.0))
+    }
+
+    pub fn speed<F>(curve: &F, t: f64) -> Result<f64, &'static str>
+    where
+        F: Fn(f64) -> Result<(f64, f64), &'static str>,
+    {
+        let (dx, dy) = velocity(curve, t)?;
+        Ok(dx.hypot(dy))
+    }
+
+    pub fn unit_tangent<F>(curve: &F, t: f64) -> Result<(f64, f64), &'static str>
+    where
+        F: Fn(f64) -> Result<(f64, f64), &'static str>,
+    {
+        let (dx, dy) = velocity(curve, t)?;
+        let norm = dx.hypot(dy);
+        if norm < EPSILON {
+            return Err(ERR_STATIONARY);
+        }
+        Ok((dx / norm, dy / norm))
+    }
+
+    // 切向量逆时针旋转90°
+    pub fn unit_normal<F>(curve: &F, t: f64) -> Result<(f64, f64), &'static str>
+    where
+        F: Fn(f64) -> Result<(f64, f64), &'static str>,
+    {
+        let (tx, ty) = unit_tangent(curve, t)?;
+        Ok((-ty, tx))
+    }
+
+    pub fn tangent_line<F>(curve: &F, t: f64) -> Result<Line, &'static str>
+    where
+        F: Fn(f64) -> Result<(f64, f64), &'static str>,
+    {
+        let (x, y) = evaluate(curve, t)?;
+        let (tx, ty) = unit_tangent(curve, t)?;
+        Line::through_points(x, y, x + tx, y + ty)
+    }
+
+    pub fn normal_line<F>(curve: &F, t: f64) -> Result<Line, &'static str>
+    where
+        F: Fn(f64) -> Result<(f64, f64), &'static str>,
+    {
+        let (x, y) = evaluate(curve, t)?;
+        let (nx, ny) = unit_normal(curve, t)?;
+        Line::through_points(x, y, x + nx, y + ny)
+    }
+
+    // 有向曲率 κ = (x'y'' - y'x'') / (x'² + y'²)^(3/2)
+    pub fn curvature<F>(curve: &F, t: f64) -> Result<f64, &'static str>
+    where
+        F: Fn(f64) -> Result<(f64, f64), &'static str>,
+    {
+        let (dx, dy) = velocity(curve, t)?;
+        let (ddx, ddy) = acceleration(curve, t)?;
+        let norm = dx.hypot(dy);
+        if norm < EPSILON {
+            return Err(ERR_STATIONARY);
+        }
+        Ok((dx * ddy - dy * ddx) / norm.powi(3))
+    }
+
+    fn adaptive_simpson<G>(g: &G, a: f64, b: f64, values: (f64, f64, f64), whole: f64, tolerance: f64, depth: u32) -> Result<f64, &'static str>
+    where
+        G: Fn(f64) -> Result<f64, &'static str>,
+    {
+        let (fa, fm, fb) = values;
+        let m = (a + b) / 2.0;
+        let lm = (a + m) / 2.0;
+        let rm = (m + b) / 2.0;
+        let flm = g(lm)?;
+        let frm = g(rm)?;
+        let left = (m - a) / 6.0 * (fa + 4.0 * flm + fm);
+        let right = (b - m) / 6.0 * (fm + 4.0 * frm + fb);
+        let delta = left + right - whole;
+        if delta.abs() <= 15.0 * tolerance {
+            return Ok(left + right + delta / 15.0);
+        }
+        if depth == 0 {
+            return Err(ERR_NOT_CONVERGED);
+        }
+        let left = adaptive_simpson(g, a, m, (fa, flm, fm), left, tolerance / 2.0, depth - 1)?;
+        let right = adaptive_simpson(g, m, b, (fm, frm, fb), right, tolerance / 2.0, depth - 1)?;
+        Ok(left + right)
+    }
+
+    pub fn arc_length<F>(curve: &F, t0: f64, t1: f64) -> Result<f64, &'static str>
+    where
+        F: Fn(f64) -> Result<(f64, f64), &'static str>,
+    {
+        if t0 > t1 {
+            return Err(ERR_INVALID_INTERVAL);
+        }
+        if t0 == t1 {
+            return Ok(0.0);
+        }
+        let g = |t: f64| speed(curve, t);
+        let m = (t0 + t1) / 2.0;
+        let (fa, fm, fb) = (g(t0)?, g(m)?, g(t1)?);
+        let whole = (t1 - t0) / 6.0 * (fa + 4.0 * fm + fb);
+        // 弧长量级很大时, 绝对容差低于浮点舍入误差, 按积分估计值放缩
+        let tolerance = SIMPSON_TOLERANCE * whole.abs().max(1.0);
+        adaptive_simpson(&g, t0, t1, (fa, fm, fb), whole, tolerance, SIMPSON_DEPTH)
+    }
+
+    // 先等距采样找到最近的采样点, 再在相邻区间内黄金分割搜索
+    pub fn closest_point<F>(curve: &F, px: f64, py: f64, t0: f64, t1: f64, samples: usize) -> Result<ClosestPoint, &'static str>
+    where
+        F: Fn(f64) -> Result<(f64, f64), &'static str>,
+    {
+        if t0 > t1 {
+            return Err(ERR_INVALID_INTERVAL);
+        }
+        if samples < 2 {
+            return Err(ERR_INVALID_SAMPLES);
+        }
+        let distance_sq = |t: f64| -> Result<f64, &'static str> {
+            let (x, y) = evaluate(curve, t)?;
+            Ok((x - px).powi(2) + (y - py).powi(2))
+        };
+        let step = (t1 - t0) / (samples - 1) as f64;
+        let mut best_index = 0;
+        let mut best = f64::INFINITY;
+        for i in 0..samples {
+            let d = distance_sq(t0 + step * i as f64)?;
+            if d < best {
+                best = d;
+                best_index = i;
+            }
+        }
+
+        let mut lo = t0 + step * best_index.saturating_sub(1) as f64;
+        let mut hi = (t0 + step * (best_index + 1) as f64).min(t1);
+        let ratio = (5f64.sqrt() - 1.0) / 2.0;
+        let mut c = hi - ratio * (hi - lo);
+        let mut d = lo + ratio * (hi - lo);
+        let mut fc = distance_sq(c)?;
+        let mut fd = distance_sq(d)?;
+        for _ in 0..GOLDEN_SECTION_STEPS {
+            // t 很大时相邻浮点数的间距可能超过 EPSILON, 此时以浮点间距为准
+            if hi - lo <= EPSILON.max(4.0 * f64::EPSILON * lo.abs().max(hi.abs())) {
+                break;
+            }
+            if fc < fd {
+                hi = d;
+                d = c;
+                fd = fc;
+                c = hi - ratio * (hi - lo);
+                fc = distance_sq(c)?;
+            } else {
+                lo = c;
+                c = d;
+                fc = fd;
+                d = lo + ratio * (hi - lo);
+                fd = distance_sq(d)?;
+            }
+        }
+        let t = (lo + hi) / 2.0;
+        let point = evaluate(curve, t)?;
+        Ok(ClosestPoint {
+            t,
+            point,
+            distance: ((point.0 - px).powi(2) + (point.1 - py).powi(2)).sqrt(),
+        })
+    }
+}
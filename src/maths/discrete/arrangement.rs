@@ -0,0 +1,439 @@
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+pub mod arrangement {
+    use crate::maths::discrete::counting::combinatorics::{combination, permutation};
+    use crate::maths::discrete::generators::generators::Permutations;
+
+    const ERR_TOO_MANY_ITEMS: &str = "元素个数不能超过20";
+    const ERR_INVALID_ITEM: &str = "约束中的元素编号越界或重复";
+    const ERR_INVALID_POSITION: &str = "约束中的位置越界";
+    const ERR_GROUP_TOO_SMALL: &str = "相邻、不相邻与定序约束至少涉及2个元素";
+    const ERR_NO_METHOD: &str = "没有适用的常规方法, 且规模过大无法枚举";
+    const ERR_INVALID_GROUPS: &str = "组数必须为正且不超过元素个数";
+    const ERR_INVALID_SIZES: &str = "各组人数须为正, 组数一致且总和等于元素个数";
+    const ERR_OVERFLOW: &str = "方法数超出u64范围";
+
+    // 超过该规模时不再用枚举验证
+    const MAX_ITEMS: usize = 20;
+    const MAX_BRUTE_FORCE_ITEMS: usize = 10;
+    const MAX_BRUTE_FORCE_CASES: u64 = 10_000_000;
+
+    // 元素用 0..n 编号, 位置也从 0 开始
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Constraint {
+        // 这些元素必须相邻 (内部顺序任意)
+        Adjacent(Vec<usize>),
+        // 这些元素两两不相邻
+        NotAdjacent(Vec<usize>),
+        // 这些元素从左到右按给定顺序出现
+        Ordered(Vec<usize>),
+        At(usize, usize),
+        NotAt(usize, Vec<usize>),
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Technique {
+        // 捆绑法: 相邻元素视为一个整体, 再乘以内部排列数
+        Bundling,
+        // 插空法: 先排其余元素, 再把不相邻元素插入空位
+        GapInsertion,
+        // 定序问题: 全排列除以定序元素的排列数
+        FixedOrder,
+        // 容斥原理: 减去落在禁止位置上的排列
+        InclusionExclusion,
+        // 先分组再分配
+        GroupingThenArranging,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Method {
+        // 不含约束时为空, 即直接全排列
+        Formula(Vec<Technique>),
+        BruteForce,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Solution {
+        pub count: u64,
+        pub method: Method,
+        // 规模允许时的枚举结果, 用于核对常规方法
+        pub brute_force: Option<u64>,
+    }
+
+    impl Solution {
+        pub fn verified(&self) -> Option<bool> {
+            self.brute_force.map(|b| b == self.count)
+        }
+    }
+
+    fn factorial(n: usize) -> u64 {
+        permutation(n as u64, n as u64).unwrap_or(0)
+    }
+
+    // n 个不同元素排成一排
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Arrangement {
+        items: usize,
+        constraints: Vec<Constraint>,
+    }
+
+    impl Arrangement {
+        pub fn new(items: usize) -> Arrangement {
+            Arrangement { items, constraints: Vec::new() }
+        }
+
+        fn push(mut self, constraint: Constraint) -> Arrangement {
+            self.constraints.push(constraint);
+            self
+        }
+
+        pub fn adjacent(self, items: &[usize]) -> Arrangement {
+            self.push(Constraint::Adjacent(items.to_vec()))
+        }
+
+        pub fn not_adjacent(self, items: &[usize]) -> Arrangement {
+            self.push(Constraint::NotAdjacent(items.to_vec()))
+        }
+
+        pub fn ordered(self, items: &[usize]) -> Arrangement {
+            self.push(Constraint::Ordered(items.to_vec()))
+        }
+
+        pub fn at(self, item: usize, position: usize) -> Arrangement {
+            self.push(Constraint::At(item, position))
+        }
+
+        pub fn not_at(self, item: usize, positions: &[usize]) -> Arrangement {
+            self.push(Constraint::NotAt(item, positions.to_vec()))
+        }
+
+        // 不站在两端
+        pub fn not_at_ends(self, item: usize) -> Arrangement {
+            let last = self.items.saturating_sub(1);
+            self.push(Constraint::NotAt(item, vec![0, last]))
+        }
+
+        pub fn constraints(&self) -> &[Constraint] {
+            &self.constraints
+        }
+
+        fn validate(&self) -> Result<(), &'static str> {
+            let n = self.items;
+            if n > MAX_ITEMS {
+                return Err(ERR_TOO_MANY_ITEMS);
+            }
+            for c in &self.constraints {
+                match c {
+                    Constraint::Adjacent(g) | Constraint::NotAdjacent(g) | Constraint::Ordered(g) => {
+                        if g.len() < 2 {
+                            return Err(ERR_GROUP_TOO_SMALL);
+                        }
+                        let mut seen = vec![false; n];
+                        for &i in g {
+                            if i >= n || seen[i] {
+                                return Err(ERR_INVALID_ITEM);
+                            }
+                            seen[i] = true;
+                        }
+                    }
+                    Constraint::At(i, p) => {
+                        if *i >= n {
+                            return Err(ERR_INVALID_ITEM);
+                        }
+                        if *p >= n {
+                            return Err(ERR_INVALID_POSITION);
+                        }
+                    }
+                    Constraint::NotAt(i, ps) => {
+                        if *i >= n {
+                            return Err(ERR_INVALID_ITEM);
+                        }
+                        if ps.iter().any(|&p| p >= n) {
+                            return Err(ERR_INVALID_POSITION);
+                        }
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        // seats[p] 为第 p 个位置上的元素
+        fn satisfied(&self, seats: &[usize]) -> bool {
+            let mut position = vec![0; seats.len()];
+            for (p, &i) in seats.iter().enumerate() {
+                position[i] = p;
+            }
+            self.constraints.iter().all(|c| match c {
+                Constraint::Adjacent(g) => {
+                    let ps: Vec<usize> = g.iter().map(|&i| position[i]).collect();
+                    ps.iter().max().unwrap_or(&0) - ps.iter().min().unwrap_or(&0) + 1 == g.len()
+                }
+                Constraint::NotAdjacent(g) => g
+                    .iter()
+                    .all(|&a| g.iter().all(|&b| a == b || position[a].abs_diff(position[b]) != 1)),
+                Constraint::Ordered(g) => g.windows(2).all(|w| position[w[0]] < position[w[1]]),
+                Constraint::At(i, p) => position[*i] == *p,
+                Constraint::NotAt(i, ps) => !ps.contains(&position[*i]),
+            })
+        }
+
+        pub fn brute_force(&self) -> Result<u64, &'static str> {
+            self.validate()?;
+            if self.items > MAX_BRUTE_FORCE_ITEMS {
+                return Err(ERR_NO_METHOD);
+            }
+            let items: Vec<usize> = (0..self.items).collect();
+            Ok(Permutations::new(&items, self.items)?.filter(|seats| self.satisfied(seats)).count() as u64)
+        }
+
+        // 按约束类型选用常规方法, 不属于常见题型时返回 None
+        fn formula(&self) -> Option<(u64, Vec<Technique>)> {
+            let n = self.items;
+            let (mut bundles, mut separated, mut ordered, mut forbidden) = (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+            for c in &self.constraints {
+                match c {
+                    Constraint::Adjacent(g) => bundles.push(g.clone()),
+                    Constraint::NotAdjacent(g) => separated.push(g.clone()),
+                    Constraint::Ordered(g) => ordered.push(g.clone()),
+                    Constraint::At(i, p) => forbidden.extend((0..n).filter(|q| q != p).map(|q| (*i, q))),
+                    Constraint::NotAt(i, ps) => forbidden.extend(ps.iter().map(|&q| (*i, q))),
+                }
+            }
+            let disjoint = |groups: &[Vec<usize>]| {
+                let mut seen = vec![false; n];
+                groups.iter().flatten().all(|&i| !std::mem::replace(&mut seen[i], true))
+            };
+
+            if !ordered.is_empty() {
+                if self.constraints.len() != ordered.len() || !disjoint(&ordered) {
+                    return None;
+                }
+                let divisor: u64 = ordered.iter().map(|g| factorial(g.len())).product();
+                return Some((factorial(n) / divisor, vec![Technique::FixedOrder]));
+            }
+            if !disjoint(&bundles) {
+                return None;
+            }
+
+            // 捆绑后的单元: 每个捆绑组占一个单元, 其余元素各占一个
+            let mut unit = vec![usize::MAX; n];
+            for (u, g) in bundles.iter().enumerate() {
+                g.iter().for_each(|&i| unit[i] = u);
+            }
+            let mut units = bundles.len();
+            for slot in unit.iter_mut().filter(|u| **u == usize::MAX) {
+                *slot = units;
+                units += 1;
+            }
+            let inner: u64 = bundles.iter().map(|g| factorial(g.len())).product();
+            let mut techniques = if bundles.is_empty() { Vec::new() } else { vec![Technique::Bundling] };
+
+            if !separated.is_empty() {
+                let group = &separated[0];
+                if separated.len() > 1 || !forbidden.is_empty() || group.iter().any(|&i| unit[i] < bundles.len()) {
+                    return None;
+                }
+                let rest = units - group.len();
+                let gaps = permutation(rest as u64 + 1, group.len() as u64).unwrap_or(0);
+                techniques.push(Technique::GapInsertion);
+                return Some((factorial(rest) * gaps * inner, techniques));
+            }
+
+            if forbidden.is_empty() {
+                return Some((factorial(units) * inner, techniques));
+            }
+            // 有捆绑时只处理两端限制, 此时元素在两端等价于其单元在两端
+            if !bundles.is_empty() {
+                let ends_only = forbidden.iter().all(|&(i, p)| unit[i] >= bundles.len() && (p == 0 || p == n - 1));
+                if !ends_only {
+                    return None;
+                }
+            }
+            let mut cells: Vec<(usize, usize)> = forbidden
+                .iter()
+                .map(|&(i, p)| (unit[i], if p == n - 1 { units - 1 } else { p }))
+                .collect();
+            cells.sort_unstable();
+            cells.dedup();
+            let rooks = rook_numbers(&cells);
+            let total = rooks.iter().enumerate().fold(0i128, |acc, (k, &r)| {
+                let term = r as i128 * factorial(units - k) as i128;
+                if k % 2 == 0 {
+                    acc + term
+                } else {
+                    acc - term
+                }
+            });
+            techniques.push(Technique::InclusionExclusion);
+            Some((total as u64 * inner, techniques))
+        }
+
+        pub fn solve(&self) -> Result<Solution, &'static str> {
+            self.validate()?;
+            let brute_force = self.brute_force().ok();
+            match self.formula() {
+                Some((count, techniques)) => Ok(Solution {
+                    count,
+                    method: Method::Formula(techniques),
+                    brute_force,
+                }),
+                None => {
+                    let count = brute_force.ok_or(ERR_NO_METHOD)?;
+                    Ok(Solution {
+                        count,
+                        method: Method::BruteForce,
+                        brute_force,
+                    })
+                }
+            }
+        }
+    }
+
+    // 禁止格 (行, 列) 上放 k 个互不同行同列的车的方法数 r_k
+    fn rook_numbers(cells: &[(usize, usize)]) -> Vec<u64> {
+        fn place(cells: &[(usize, usize)], rows: u32, columns: u32, k: usize, counts: &mut Vec<u64>) {
+            if counts.len() <= k {
+                counts.push(0);
+            }
+            counts[k] += 1;
+            for (j, &(r, c)) in cells.iter().enumerate() {
+                if rows & (1 << r) == 0 && columns & (1 << c) == 0 {
+                    place(&cells[j + 1..], rows | (1 << r), columns | (1 << c), k + 1, counts);
+                }
+            }
+        }
+        let mut counts = Vec::new();
+        place(cells, 0, 0, 0, &mut counts);
+        counts
+    }
+
+    // 把 n 个不同元素分成 k 组, 每组非空; labeled 时各组再分配给 k 个不同对象
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct GroupDistribution {
+        items: usize,
+        groups: usize,
+        sizes: Option<Vec<usize>>,
+        labeled: bool,
+    }
+
+    impl GroupDistribution {
+        pub fn new(items: usize, groups: usize) -> GroupDistribution {
+            GroupDistribution {
+                items,
+                groups,
+                sizes: None,
+                labeled: true,
+            }
+        }
+
+        // 指定各组人数, 如 2, 2, 1
+        pub fn sizes(self, sizes: &[usize]) -> GroupDistribution {
+            GroupDistribution {
+                sizes: Some(sizes.to_vec()),
+                ..self
+            }
+        }
+
+        // 只分组, 不分配给不同对象
+        pub fn unlabeled(self) -> GroupDistribution {
+            GroupDistribution { labeled: false, ..self }
+        }
+
+        fn validate(&self) -> Result<(), &'static str> {
+            if self.items > MAX_ITEMS {
+                return Err(ERR_TOO_MANY_ITEMS);
+            }
+            if self.groups == 0 || self.groups > self.items {
+                return Err(ERR_INVALID_GROUPS);
+            }
+            let invalid = |sizes: &&Vec<usize>| sizes.len() != self.groups || sizes.contains(&0) || sizes.iter().sum::<usize>() != self.items;
+            if self.sizes.as_ref().filter(invalid).is_some() {
+                return Err(ERR_INVALID_SIZES);
+            }
+            Ok(())
+        }
+
+        // 按各组人数分组的方法数 n! / (Π s_i! · Π 相同人数组数的阶乘)
+        fn groupings(&self, sizes: &[usize]) -> Result<u64, &'static str> {
+            let mut remaining = self.items as u64;
+            let mut count: u64 = 1;
+            for &s in sizes {
+                count = count.checked_mul(combination(remaining, s as u64)?).ok_or(ERR_OVERFLOW)?;
+                remaining -= s as u64;
+            }
+            let mut sorted = sizes.to_vec();
+            sorted.sort_unstable();
+            let mut repeated: u64 = 1;
+            for run in sorted.chunk_by(|a, b| a == b) {
+                repeated = repeated.checked_mul(factorial(run.len())).ok_or(ERR_OVERFLOW)?;
+            }
+            Ok(count / repeated)
+        }
+
+        pub fn brute_force(&self) -> Result<u64, &'static str> {
+            self.validate()?;
+            let (n, k) = (self.items as u32, self.groups as u64);
+            let cases = k.checked_pow(n).filter(|&c| c <= MAX_BRUTE_FORCE_CASES).ok_or(ERR_NO_METHOD)?;
+            let mut wanted = self.sizes.clone();
+            if let Some(w) = wanted.as_mut() {
+                w.sort_unstable();
+            }
+            let mut count = 0;
+            for code in 0..cases {
+                // 第 i 个元素去往第 (code 的 k 进制第 i 位) 组
+                let mut sizes = vec![0; self.groups];
+                let mut rest = code;
+                for _ in 0..n {
+                    sizes[(rest % k) as usize] += 1;
+                    rest /= k;
+                }
+                let ok = match &wanted {
+                    Some(w) => {
+                        sizes.sort_unstable();
+                        sizes == *w
+                    }
+                    None => !sizes.contains(&0),
+                };
+                count += ok as u64;
+            }
+            // 各组非空时, 不计组的顺序恰好是有序情形除以 k!
+            Ok(if self.labeled { count } else { count / factorial(self.groups) })
+        }
+
+        pub fn solve(&self) -> Result<Solution, &'static str> {
+            self.validate()?;
+            let partitions = match &self.sizes {
+                Some(sizes) => vec![sizes.clone()],
+                None => partitions(self.items, self.groups, self.items),
+            };
+            let grouped = partitions
+                .iter()
+                .try_fold(0u64, |acc, p| self.groupings(p).and_then(|g| acc.checked_add(g).ok_or(ERR_OVERFLOW)))?;
+            let count = if self.labeled { grouped.checked_mul(factorial(self.groups)).ok_or(ERR_OVERFLOW)? } else { grouped };
+            Ok(Solution {
+                count,
+                method: Method::Formula(vec![Technique::GroupingThenArranging]),
+                brute_force: self.brute_force().ok(),
+            })
+        }
+    }
+
+    // n 拆成 k 个不超过 largest 的正整数之和, 各部分从大到小
+    fn partitions(n: usize, k: usize, largest: usize) -> Vec<Vec<usize>> {
+        if k == 0 {
+            return if n == 0 { vec![Vec::new()] } else { Vec::new() };
+        }
+        let mut result = Vec::new();
+        for first in (1..=largest.min(n)).rev() {
+            for mut rest in partitions(n - first, k - 1, first) {
+                rest.insert(0, first);
+                result.push(rest);
+            }
+        }
+        result
+    }
+}
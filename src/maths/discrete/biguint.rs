@@ -0,0 +1,222 @@
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+pub mod big_integers {
+    use std::cmp::Ordering;
+    use std::fmt;
+    use std::iter::{Product, Sum};
+    use std::ops::{Add, AddAssign, Mul};
+
+    const BASE: u64 = 1 << 32;
+    // 十进制输出时每次取出 9 位
+    const DECIMAL_CHUNK: u32 = 1_000_000_000;
+
+    // 无符号大整数, 以 2^32 为基的小端序存储, 最高位非零
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+    pub struct BigUint {
+        limbs: Vec<u32>,
+    }
+
+    impl BigUint {
+        pub fn zero() -> BigUint {
+            BigUint { limbs: Vec::new() }
+        }
+
+        pub fn one() -> BigUint {
+            BigUint::from(1u64)
+        }
+
+        fn trimmed(mut limbs: Vec<u32>) -> BigUint {
+            while limbs.last() == Some(&0) {
+                limbs.pop();
+            }
+            BigUint { limbs }
+        }
+
+        pub fn is_zero(&self) -> bool {
+            self.limbs.is_empty()
+        }
+
+        pub fn to_u64(&self) -> Option<u64> {
+            match self.limbs.len() {
+                0 => Some(0),
+                1 => Some(self.limbs[0] as u64),
+                2 => Some(self.limbs[0] as u64 | (self.limbs[1] as u64) << 32),
+                _ => None,
+            }
+        }
+
+        pub fn mul_small(&self, m: u32) -> BigUint {
+            let mut carry = 0u64;
+            let mut limbs: Vec<u32> = self
+                .limbs
+                .iter()
+                .map(|&d| {
+                    let v = d as u64 * m as u64 + carry;
+                    carry = v >> 32;
+                    v as u32
+                })
+                .collect();
+            if carry > 0 {
+                limbs.push(carry as u32);
+            }
+            BigUint::trimmed(limbs)
+        }
+
+        // 除以单个数位, 返回 (商, 余数)
+        pub fn div_rem_small(&self, d: u32) -> Option<(BigUint, u32)> {
+            if d == 0 {
+                return None;
+            }
+            let mut rem = 0u64;
+            let mut limbs = vec![0; self.limbs.len()];
+            for i in (0..self.limbs.len()).rev() {
+                let v = rem * BASE + self.limbs[i] as u64;
+                limbs[i] = (v / d as u64) as u32;
+                rem = v % d as u64;
+            }
+            Some((BigUint::trimmed(limbs), rem as u32))
+        }
+
+        pub fn pow(&self, mut exp: u32) -> BigUint {
+            let mut base = self.clone();
+            let mut result = BigUint::one();
+            while exp > 0 {
+                if exp & 1 == 1 {
+                    result = &result * &base;
+                }
+                base = &base * &base;
+                exp >>= 1;
+            }
+            result
+        }
+    }
+
+    impl From<u64> for BigUint {
+        fn from(n: u64) -> BigUint {
+            BigUint::trimmed(vec![n as u32, (n >> 32) as u32])
+        }
+    }
+
+    impl Ord for BigUint {
+        fn cmp(&self, other: &BigUint) -> Ordering {
+            self.limbs
+                .len()
+                .cmp(&other.limbs.len())
+                .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+        }
+    }
+
+    impl PartialOrd for BigUint {
+        fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Add for &BigUint {
+        type Output = BigUint;
+
+        fn add(self, other: &BigUint) -> BigUint {
+            let (long, short) = if self.limbs.len() >= other.limbs.len() { (self, other) } else { (other, self) };
+            let mut carry = 0u64;
+            let mut limbs: Vec<u32> = long
+                .limbs
+                .iter()
+                .enumerate()
+                .map(|(i, &d)| {
+                    let v = d as u64 + short.limbs.get(i).copied().unwrap_or(0) as u64 + carry;
+                    carry = v >> 32;
+                    v as u32
+                })
+                .collect();
+            if carry > 0 {
+                limbs.push(carry as u32);
+            }
+            BigUint { limbs }
+        }
+    }
+
+    impl Add for BigUint {
+        type Output = BigUint;
+
+        fn add(self, other: BigUint) -> BigUint {
+            &self + &other
+        }
+    }
+
+    impl AddAssign<&BigUint> for BigUint {
+        fn add_assign(&mut self, other: &BigUint) {
+            *self = &*self + other;
+        }
+    }
+
+    impl Mul for &BigUint {
+        type Output = BigUint;
+
+        fn mul(self, other: &BigUint) -> BigUint {
+            if self.is_zero() || other.is_zero() {
+                return BigUint::zero();
+            }
+            let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+            for (i, &a) in self.limbs.iter().enumerate() {
+                let mut carry = 0u64;
+                for (j, &b) in other.limbs.iter().enumerate() {
+                    let v = limbs[i + j] as u64 + a as u64 * b as u64 + carry;
+                    limbs[i + j] = v as u32;
+                    carry = v >> 32;
+                }
+                limbs[i + other.limbs.len()] = carry as u32;
+            }
+            BigUint::trimmed(limbs)
+        }
+    }
+
+    impl Mul for BigUint {
+        type Output = BigUint;
+
+        fn mul(self, other: BigUint) -> BigUint {
+            &self * &other
+        }
+    }
+
+    impl Sum for BigUint {
+        fn sum<I: Iterator<Item = BigUint>>(iter: I) -> BigUint {
+            iter.fold(BigUint::zero(), |acc, x| &acc + &x)
+        }
+    }
+
+    impl<'a> Sum<&'a BigUint> for BigUint {
+        fn sum<I: Iterator<Item = &'a BigUint>>(iter: I) -> BigUint {
+            iter.fold(BigUint::zero(), |acc, x| &acc + x)
+        }
+    }
+
+    impl Product for BigUint {
+        fn product<I: Iterator<Item = BigUint>>(iter: I) -> BigUint {
+            iter.fold(BigUint::one(), |acc, x| &acc * &x)
+        }
+    }
+
+    impl fmt::Display for BigUint {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            if self.is_zero() {
+                return f.pad("0");
+            }
+            let mut chunks = Vec::new();
+            let mut rest = self.clone();
+            while !rest.is_zero() {
+                let (q, r) = rest.div_rem_small(DECIMAL_CHUNK).unwrap_or_default();
+                chunks.push(r);
+                rest = q;
+            }
+            let mut digits = chunks.pop().unwrap_or(0).to_string();
+            for chunk in chunks.iter().rev() {
+                digits.push_str(&format!("{:09}", chunk));
+            }
+            f.pad(&digits)
+        }
+    }
+}
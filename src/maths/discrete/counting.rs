@@ -5,28 +5,52 @@
  */
 
 pub mod combinatorics {
-    const ERR_NEGATIVE_VALUE: &str = "数值不能为负数";
     const ERR_INVALID_INPUT: &str = "n必须大于等于k";
     const ERR_EMPTY_OPTIONS: &str = "选项列表不能为空";
+    const ERR_OVERFLOW: &str = "计数超出u64范围";
 
-    fn factorial(n: u64) -> u64 {
-        (1..=n).product()
+    // C(n, k), 逐项相乘时保持为整数, 溢出 u128 时返回 None
+    pub fn binomial(n: u64, k: u64) -> Option<u128> {
+        if k > n {
+            return Some(0);
+        }
+        let k = k.min(n - k);
+        (0..k).try_fold(1u128, |acc, i| acc.checked_mul((n - i) as u128).map(|v| v / (i + 1) as u128))
+    }
+
+    // A(n, k) = n(n-1)...(n-k+1)
+    pub fn falling_factorial(n: u64, k: u64) -> Option<u128> {
+        if k > n {
+            return Some(0);
+        }
+        (n - k + 1..=n).try_fold(1u128, |acc, v| acc.checked_mul(v as u128))
+    }
+
+    // 多重集排列数 (Σ c_i)! / Π c_i!
+    pub fn multinomial(counts: &[u64]) -> Option<u128> {
+        let mut total = 0;
+        counts.iter().try_fold(1u128, |acc, &c| {
+            total += c;
+            acc.checked_mul(binomial(total, c)?)
+        })
+    }
+
+    fn to_u64(value: Option<u128>) -> Result<u64, &'static str> {
+        value.and_then(|v| u64::try_from(v).ok()).ok_or(ERR_OVERFLOW)
     }
 
     pub fn permutation(n: u64, k: u64) -> Result<u64, &'static str> {
         if n < k {
             return Err(ERR_INVALID_INPUT);
         }
-        Ok(factorial(n) / factorial(n - k))
+        to_u64(falling_factorial(n, k))
     }
 
     pub fn combination(n: u64, k: u64) -> Result<u64, &'static str> {
         if n < k {
             return Err(ERR_INVALID_INPUT);
         }
-        let k = k.min(n - k);
-        let numerator: u64 = (n - k + 1..=n).product();
-        Ok(numerator / factorial(k))
+        to_u64(binomial(n, k))
     }
 
     pub fn combination_identity(n: u64, k: u64) -> Result<u64, &'static str> {
@@ -0,0 +1,444 @@
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+pub mod generators {
+    use crate::maths::discrete::counting::combinatorics::{binomial, falling_factorial, multinomial};
+
+    const ERR_INVALID_INPUT: &str = "n必须大于等于k";
+    const ERR_INVALID_INDEX: &str = "下标越界、重复或未按要求排列";
+    const ERR_RANK_OUT_OF_RANGE: &str = "序号超出对象总数";
+    const ERR_OVERFLOW: &str = "计数超出u128范围";
+    const ERR_NOT_A_PERMUTATION: &str = "不是该多重集的排列";
+
+    // 按字典序枚举的对象都以下标序列表示, 再映射为原切片中的元素
+
+    fn pick<T: Clone>(items: &[T], indices: &[usize]) -> Vec<T> {
+        indices.iter().map(|&i| items[i].clone()).collect()
+    }
+
+    // k-排列: 从 n 个元素中取 k 个按顺序排列
+    pub struct Permutations<'a, T> {
+        items: &'a [T],
+        indices: Vec<usize>,
+        used: Vec<bool>,
+        done: bool,
+    }
+
+    impl<'a, T: Clone> Permutations<'a, T> {
+        pub fn new(items: &'a [T], k: usize) -> Result<Permutations<'a, T>, &'static str> {
+            if k > items.len() {
+                return Err(ERR_INVALID_INPUT);
+            }
+            let mut used = vec![false; items.len()];
+            used[..k].iter_mut().for_each(|u| *u = true);
+            Ok(Permutations {
+                items,
+                indices: (0..k).collect(),
+                used,
+                done: false,
+            })
+        }
+
+        fn advance(&mut self) -> bool {
+            let (n, k) = (self.items.len(), self.indices.len());
+            for pos in (0..k).rev() {
+                self.used[self.indices[pos]] = false;
+                if let Some(next) = (self.indices[pos] + 1..n).find(|&i| !self.used[i]) {
+                    self.indices[pos] = next;
+                    self.used[next] = true;
+                    // 其后各位依次填入最小的未用下标
+                    let mut free = (0..n).filter(|&i| !self.used[i]).collect::<Vec<usize>>().into_iter();
+                    for slot in pos + 1..k {
+                        let i = free.next().unwrap_or(0);
+                        self.indices[slot] = i;
+                        self.used[i] = true;
+                    }
+                    return true;
+                }
+            }
+            false
+        }
+    }
+
+    impl<T: Clone> Iterator for Permutations<'_, T> {
+        type Item = Vec<T>;
+
+        fn next(&mut self) -> Option<Vec<T>> {
+            if self.done {
+                return None;
+            }
+            let current = pick(self.items, &self.indices);
+            self.done = !self.advance();
+            Some(current)
+        }
+    }
+
+    // k-组合: 严格递增的下标序列
+    pub struct Combinations<'a, T> {
+        items: &'a [T],
+        indices: Vec<usize>,
+        done: bool,
+    }
+
+    impl<'a, T: Clone> Combinations<'a, T> {
+        pub fn new(items: &'a [T], k: usize) -> Result<Combinations<'a, T>, &'static str> {
+            if k > items.len() {
+                return Err(ERR_INVALID_INPUT);
+            }
+            Ok(Combinations {
+                items,
+                indices: (0..k).collect(),
+                done: false,
+            })
+        }
+    }
+
+    impl<T: Clone> Iterator for Combinations<'_, T> {
+        type Item = Vec<T>;
+
+        fn next(&mut self) -> Option<Vec<T>> {
+            if self.done {
+                return None;
+            }
+            let current = pick(self.items, &self.indices);
+            let (n, k) = (self.items.len(), self.indices.len());
+            match (0..k).rev().find(|&pos| self.indices[pos] < n - k + pos) {
+                Some(pos) => {
+                    self.indices[pos] += 1;
+                    for slot in pos + 1..k {
+                        self.indices[slot] = self.indices[slot - 1] + 1;
+                    }
+                }
+                None => self.done = true,
+            }
+            Some(current)
+        }
+    }
+
+    // 可重复组合: 单调不减的下标序列
+    pub struct CombinationsWithReplacement<'a, T> {
+        items: &'a [T],
+        indices: Vec<usize>,
+        done: bool,
+    }
+
+    impl<'a, T: Clone> CombinationsWithReplacement<'a, T> {
+        pub fn new(items: &'a [T], k: usize) -> CombinationsWithReplacement<'a, T> {
+            CombinationsWithReplacement {
+                items,
+                indices: vec![0; k],
+                done: items.is_empty() && k > 0,
+            }
+        }
+    }
+
+    impl<T: Clone> Iterator for CombinationsWithReplacement<'_, T> {
+        type Item = Vec<T>;
+
+        fn next(&mut self) -> Option<Vec<T>> {
+            if self.done {
+                return None;
+            }
+            let current = pick(self.items, &self.indices);
+            let n = self.items.len();
+            match (0..self.indices.len()).rev().find(|&pos| self.indices[pos] + 1 < n) {
+                Some(pos) => {
+                    let next = self.indices[pos] + 1;
+                    self.indices[pos..].iter_mut().for_each(|i| *i = next);
+                }
+                None => self.done = true,
+            }
+            Some(current)
+        }
+    }
+
+    // 全部子集, 按递增下标序列的字典序: {}, {0}, {0,1}, {0,1,2}, {0,2}, {1}, ...
+    pub struct Subsets<'a, T> {
+        items: &'a [T],
+        indices: Vec<usize>,
+        done: bool,
+    }
+
+    impl<'a, T: Clone> Subsets<'a, T> {
+        pub fn new(items: &'a [T]) -> Subsets<'a, T> {
+            Subsets {
+                items,
+                indices: Vec::new(),
+                done: false,
+            }
+        }
+    }
+
+    impl<T: Clone> Iterator for Subsets<'_, T> {
+        type Item = Vec<T>;
+
+        fn next(&mut self) -> Option<Vec<T>> {
+            if self.done {
+                return None;
+            }
+            let current = pick(self.items, &self.indices);
+            let n = self.items.len();
+            match self.indices.last().copied() {
+                None if n > 0 => self.indices.push(0),
+                Some(last) if last + 1 < n => self.indices.push(last + 1),
+                _ => {
+                    self.indices.pop();
+                    match self.indices.last_mut() {
+                        Some(last) => *last += 1,
+                        None => self.done = true,
+                    }
+                }
+            }
+            Some(current)
+        }
+    }
+
+    // 多重集的全排列, 相同元素不重复计数, 按元素大小的字典序
+    pub struct MultisetPermutations<T> {
+        current: Vec<T>,
+        done: bool,
+    }
+
+    impl<T: Ord + Clone> MultisetPermutations<T> {
+        pub fn new(items: &[T]) -> MultisetPermutations<T> {
+            let mut current = items.to_vec();
+            current.sort();
+            MultisetPermutations { current, done: false }
+        }
+    }
+
+    impl<T: Ord + Clone> Iterator for MultisetPermutations<T> {
+        type Item = Vec<T>;
+
+        fn next(&mut self) -> Option<Vec<T>> {
+            if self.done {
+                return None;
+            }
+            let result = self.current.clone();
+            let v = &mut self.current;
+            match (1..v.len()).rev().find(|&i| v[i - 1] < v[i]) {
+                Some(i) => {
+                    let j = (i..v.len()).rev().find(|&j| v[i - 1] < v[j]).unwrap_or(i);
+                    v.swap(i - 1, j);
+                    v[i..].reverse();
+                }
+                None => self.done = true,
+            }
+            Some(result)
+        }
+    }
+
+    fn check_distinct(indices: &[usize], n: usize) -> Result<(), &'static str> {
+        let mut seen = vec![false; n];
+        for &i in indices {
+            if i >= n || seen[i] {
+                return Err(ERR_INVALID_INDEX);
+            }
+            seen[i] = true;
+        }
+        Ok(())
+    }
+
+    // k-排列的字典序序号, 从 0 开始
+    pub fn permutation_rank(indices: &[usize], n: usize) -> Result<u128, &'static str> {
+        check_distinct(indices, n)?;
+        let k = indices.len();
+        let mut used = vec![false; n];
+        let mut rank = 0u128;
+        for (pos, &i) in indices.iter().enumerate() {
+            let smaller = (0..i).filter(|&j| !used[j]).count() as u128;
+            let block = falling_factorial((n - pos - 1) as u64, (k - pos - 1) as u64).ok_or(ERR_OVERFLOW)?;
+            rank = smaller.checked_mul(block).and_then(|v| v.checked_add(rank)).ok_or(ERR_OVERFLOW)?;
+            used[i] = true;
+        }
+        Ok(rank)
+    }
+
+    pub fn permutation_unrank(n: usize, k: usize, rank: u128) -> Result<Vec<usize>, &'static str> {
+        if k > n {
+            return Err(ERR_INVALID_INPUT);
+        }
+        if falling_factorial(n as u64, k as u64).is_some_and(|total| rank >= total) {
+            return Err(ERR_RANK_OUT_OF_RANGE);
+        }
+        let mut free: Vec<usize> = (0..n).collect();
+        let mut rank = rank;
+        let mut result = Vec::with_capacity(k);
+        for pos in 0..k {
+            let block = falling_factorial((n - pos - 1) as u64, (k - pos - 1) as u64).ok_or(ERR_OVERFLOW)?;
+            let q = (rank / block) as usize;
+            rank %= block;
+            result.push(free.remove(q));
+        }
+        Ok(result)
+    }
+
+    // k-组合 (递增下标) 的字典序序号
+    pub fn combination_rank(indices: &[usize], n: usize) -> Result<u128, &'static str> {
+        if indices.iter().any(|&i| i >= n) || indices.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(ERR_INVALID_INDEX);
+        }
+        let k = indices.len();
+        let mut rank = 0u128;
+        let mut lo = 0;
+        for (pos, &i) in indices.iter().enumerate() {
+            // 该位取 lo..i 中的值时, 后面 k - pos - 1 位从更大的下标中任取
+            for c in lo..i {
+                let block = binomial((n - c - 1) as u64, (k - pos - 1) as u64).ok_or(ERR_OVERFLOW)?;
+                rank = rank.checked_add(block).ok_or(ERR_OVERFLOW)?;
+            }
+            lo = i + 1;
+        }
+        Ok(rank)
+    }
+
+    pub fn combination_unrank(n: usize, k: usize, rank: u128) -> Result<Vec<usize>, &'static str> {
+        if k > n {
+            return Err(ERR_INVALID_INPUT);
+        }
+        if binomial(n as u64, k as u64).is_some_and(|total| rank >= total) {
+            return Err(ERR_RANK_OUT_OF_RANGE);
+        }
+        let mut rank = rank;
+        let mut result = Vec::with_capacity(k);
+        let mut c = 0;
+        for pos in 0..k {
+            loop {
+                let block = binomial((n - c - 1) as u64, (k - pos - 1) as u64).ok_or(ERR_OVERFLOW)?;
+                if rank < block {
+                    break;
+                }
+                rank -= block;
+                c += 1;
+            }
+            result.push(c);
+            c += 1;
+        }
+        Ok(result)
+    }
+
+    // 可重复组合 a_0 <= a_1 <= ... 经 a_i + i 一一对应到 n + k - 1 个元素的 k-组合
+    pub fn multicombination_rank(indices: &[usize], n: usize) -> Result<u128, &'static str> {
+        if indices.iter().any(|&i| i >= n) || indices.windows(2).any(|w| w[0] > w[1]) {
+            return Err(ERR_INVALID_INDEX);
+        }
+        let shifted: Vec<usize> = indices.iter().enumerate().map(|(pos, &i)| i + pos).collect();
+        combination_rank(&shifted, n + indices.len().saturating_sub(1))
+    }
+
+    pub fn multicombination_unrank(n: usize, k: usize, rank: u128) -> Result<Vec<usize>, &'static str> {
+        if n == 0 && k > 0 {
+            return Err(ERR_RANK_OUT_OF_RANGE);
+        }
+        let shifted = combination_unrank(n + k.saturating_sub(1), k, rank)?;
+        Ok(shifted.iter().enumerate().map(|(pos, &i)| i - pos).collect())
+    }
+
+    // 2^m 个以某下标开头的子树大小
+    fn subtree(n: usize, c: usize) -> Result<u128, &'static str> {
+        1u128.checked_shl((n - 1 - c) as u32).filter(|_| n - 1 - c < 128).ok_or(ERR_OVERFLOW)
+    }
+
+    // 子集 (递增下标) 在 Subsets 枚举顺序中的序号
+    pub fn subset_rank(indices: &[usize], n: usize) -> Result<u128, &'static str> {
+        if indices.iter().any(|&i| i >= n) || indices.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(ERR_INVALID_INDEX);
+        }
+        let mut rank = 0u128;
+        let mut lo = 0;
+        for &i in indices {
+            rank += 1;
+            for c in lo..i {
+                rank = rank.checked_add(subtree(n, c)?).ok_or(ERR_OVERFLOW)?;
+            }
+            lo = i + 1;
+        }
+        Ok(rank)
+    }
+
+    pub fn subset_unrank(n: usize, rank: u128) -> Result<Vec<usize>, &'static str> {
+        if n < 128 && rank >> n != 0 {
+            return Err(ERR_RANK_OUT_OF_RANGE);
+        }
+        let mut rank = rank;
+        let mut result = Vec::new();
+        let mut c = 0;
+        while rank > 0 {
+            rank -= 1;
+            while rank >= subtree(n, c)? {
+                rank -= subtree(n, c)?;
+                c += 1;
+            }
+            result.push(c);
+            c += 1;
+        }
+        Ok(result)
+    }
+
+    fn tally<T: Ord + Clone>(items: &[T]) -> Vec<(T, u64)> {
+        let mut sorted = items.to_vec();
+        sorted.sort();
+        let mut counts: Vec<(T, u64)> = Vec::new();
+        for x in sorted {
+            match counts.last_mut() {
+                Some((value, count)) if *value == x => *count += 1,
+                _ => counts.push((x, 1)),
+            }
+        }
+        counts
+    }
+
+    // arrangement 作为多重集 items 的一个排列时的字典序序号
+    pub fn multiset_permutation_rank<T: Ord + Clone>(items: &[T], arrangement: &[T]) -> Result<u128, &'static str> {
+        if arrangement.len() != items.len() {
+            return Err(ERR_NOT_A_PERMUTATION);
+        }
+        let mut counts = tally(items);
+        let mut rank = 0u128;
+        for x in arrangement {
+            let here = counts
+                .iter()
+                .position(|(v, c)| v == x && *c > 0)
+                .ok_or(ERR_NOT_A_PERMUTATION)?;
+            for smaller in 0..here {
+                if counts[smaller].1 == 0 {
+                    continue;
+                }
+                counts[smaller].1 -= 1;
+                let block = multinomial(&counts.iter().map(|c| c.1).collect::<Vec<u64>>()).ok_or(ERR_OVERFLOW)?;
+                counts[smaller].1 += 1;
+                rank = rank.checked_add(block).ok_or(ERR_OVERFLOW)?;
+            }
+            counts[here].1 -= 1;
+        }
+        Ok(rank)
+    }
+
+    pub fn multiset_permutation_unrank<T: Ord + Clone>(items: &[T], rank: u128) -> Result<Vec<T>, &'static str> {
+        let mut counts = tally(items);
+        if multinomial(&counts.iter().map(|c| c.1).collect::<Vec<u64>>()).is_some_and(|total| rank >= total) {
+            return Err(ERR_RANK_OUT_OF_RANGE);
+        }
+        let mut rank = rank;
+        let mut result = Vec::with_capacity(items.len());
+        for _ in 0..items.len() {
+            for v in 0..counts.len() {
+                if counts[v].1 == 0 {
+                    continue;
+                }
+                counts[v].1 -= 1;
+                let block = multinomial(&counts.iter().map(|c| c.1).collect::<Vec<u64>>()).ok_or(ERR_OVERFLOW)?;
+                if rank < block {
+                    result.push(counts[v].0.clone());
+                    break;
+                }
+                counts[v].1 += 1;
+                rank -= block;
+            }
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,253 @@
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+pub mod special_numbers {
+    use crate::maths::discrete::biguint::big_integers::BigUint;
+    use std::fmt;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Balls {
+        Distinct,
+        Identical,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Boxes {
+        Distinct,
+        Identical,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Rule {
+        // 每盒球数不限
+        Any,
+        // 每盒至多一个球 (单射)
+        AtMostOne,
+        // 每盒至少一个球 (满射)
+        AtLeastOne,
+    }
+
+    const BALLS: [Balls; 2] = [Balls::Distinct, Balls::Identical];
+    const BOXES: [Boxes; 2] = [Boxes::Distinct, Boxes::Identical];
+    const RULES: [Rule; 3] = [Rule::Any, Rule::AtMostOne, Rule::AtLeastOne];
+
+    // n 个球放入 k 个盒子的十二种情形, entries[球][盒][规则] 与 BALLS、BOXES、RULES 的顺序对应
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct TwelvefoldTable {
+        pub balls: usize,
+        pub boxes: usize,
+        pub entries: Vec<Vec<Vec<BigUint>>>,
+    }
+
+    impl TwelvefoldTable {
+        pub fn get(&self, balls: Balls, boxes: Boxes, rule: Rule) -> &BigUint {
+            &self.entries[balls as usize][boxes as usize][rule as usize]
+        }
+    }
+
+    impl fmt::Display for TwelvefoldTable {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let labels = ["球不同, 盒不同", "球不同, 盒相同", "球相同, 盒不同", "球相同, 盒相同"];
+            let cells: Vec<Vec<String>> = (0..4)
+                .map(|row| RULES.iter().map(|&r| self.get(BALLS[row / 2], BOXES[row % 2], r).to_string()).collect())
+                .collect();
+            let header = ["任意", "每盒至多一个", "每盒至少一个"];
+            let widths: Vec<usize> = (0..3)
+                .map(|c| cells.iter().map(|row| row[c].chars().count()).chain([header[c].chars().count()]).max().unwrap_or(0))
+                .collect();
+            writeln!(f, "n = {}, k = {}", self.balls, self.boxes)?;
+            write!(f, "{:<8}", "")?;
+            for (h, w) in header.iter().zip(&widths) {
+                write!(f, " | {:>w$}", h, w = w)?;
+            }
+            for (label, row) in labels.iter().zip(&cells) {
+                write!(f, "\n{}", label)?;
+                for (cell, w) in row.iter().zip(&widths) {
+                    write!(f, " | {:>w$}", cell, w = w)?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    // 各类计数数列的记忆化表, 按需逐行扩展
+    #[derive(Debug, Clone, Default)]
+    pub struct CountingNumbers {
+        factorials: Vec<BigUint>,
+        binomials: Vec<Vec<BigUint>>,
+        // 无符号第一类斯特林数 c(n, k)
+        stirling_first: Vec<Vec<BigUint>>,
+        stirling_second: Vec<Vec<BigUint>>,
+        // 把 n 拆成恰好 k 个正整数之和的方法数 p(n, k)
+        partitions: Vec<Vec<BigUint>>,
+        bells: Vec<BigUint>,
+        catalans: Vec<BigUint>,
+    }
+
+    // 三角形递推表: row[n][k] 只对 k <= n 存储
+    fn extend_triangle<F>(table: &mut Vec<Vec<BigUint>>, n: usize, mut next: F)
+    where
+        F: FnMut(&[Vec<BigUint>], usize, usize) -> BigUint,
+    {
+        while table.len() <= n {
+            let m = table.len();
+            let row: Vec<BigUint> = (0..=m)
+                .map(|k| if m == 0 { BigUint::one() } else if k == 0 { BigUint::zero() } else { next(table, m, k) })
+                .collect();
+            table.push(row);
+        }
+    }
+
+    fn entry(table: &[Vec<BigUint>], n: usize, k: usize) -> BigUint {
+        table.get(n).and_then(|row| row.get(k)).cloned().unwrap_or_default()
+    }
+
+    impl CountingNumbers {
+        pub fn new() -> CountingNumbers {
+            CountingNumbers::default()
+        }
+
+        pub fn factorial(&mut self, n: usize) -> BigUint {
+            if self.factorials.is_empty() {
+                self.factorials.push(BigUint::one());
+            }
+            while self.factorials.len() <= n {
+                let m = self.factorials.len();
+                let next = self.factorials[m - 1].mul_small(m as u32);
+                self.factorials.push(next);
+            }
+            self.factorials[n].clone()
+        }
+
+        // 杨辉三角 C(n, k) = C(n - 1, k - 1) + C(n - 1, k), 只用加法, 与其余各表一样逐行记忆
+        pub fn binomial(&mut self, n: usize, k: usize) -> BigUint {
+            while self.binomials.len() <= n {
+                let m = self.binomials.len();
+                let row: Vec<BigUint> = (0..=m)
+                    .map(|k| if k == 0 || k == m { BigUint::one() } else { &entry(&self.binomials, m - 1, k - 1) + &entry(&self.binomials, m - 1, k) })
+                    .collect();
+                self.binomials.push(row);
+            }
+            entry(&self.binomials, n, k)
+        }
+
+        // A(n, k) = C(n, k)·k!
+        pub fn falling_factorial(&mut self, n: usize, k: usize) -> BigUint {
+            if k > n {
+                return BigUint::zero();
+            }
+            &self.binomial(n, k) * &self.factorial(k)
+        }
+
+        // (k_1 + ... + k_m)! / (k_1!...k_m!) = Π C(k_1 + ... + k_i, k_i)
+        pub fn multinomial(&mut self, counts: &[usize]) -> BigUint {
+            let mut total = 0;
+            counts
+                .iter()
+                .map(|&c| {
+                    total += c;
+                    self.binomial(total, c)
+                })
+                .product()
+        }
+
+        // c(n, k) = (n - 1)·c(n - 1, k) + c(n - 1, k - 1), 即 n 个元素排成 k 个轮换的方法数
+        pub fn stirling_first(&mut self, n: usize, k: usize) -> BigUint {
+            extend_triangle(&mut self.stirling_first, n, |t, m, k| {
+                &entry(t, m - 1, k).mul_small(m as u32 - 1) + &entry(t, m - 1, k - 1)
+            });
+            entry(&self.stirling_first, n, k)
+        }
+
+        // S(n, k) = k·S(n - 1, k) + S(n - 1, k - 1), 即 n 个元素分成 k 个非空子集的方法数
+        pub fn stirling_second(&mut self, n: usize, k: usize) -> BigUint {
+            extend_triangle(&mut self.stirling_second, n, |t, m, k| {
+                &entry(t, m - 1, k).mul_small(k as u32) + &entry(t, m - 1, k - 1)
+            });
+            entry(&self.stirling_second, n, k)
+        }
+
+        // B(n) = Σ S(n, k)
+        pub fn bell(&mut self, n: usize) -> BigUint {
+            while self.bells.len() <= n {
+                let m = self.bells.len();
+                self.stirling_second(m, 0);
+                let value = self.stirling_second[m].iter().sum();
+                self.bells.push(value);
+            }
+            self.bells[n].clone()
+        }
+
+        // C_(n+1) = C_n·2(2n + 1) / (n + 2)
+        pub fn catalan(&mut self, n: usize) -> BigUint {
+            if self.catalans.is_empty() {
+                self.catalans.push(BigUint::one());
+            }
+            while self.catalans.len() <= n {
+                let m = self.catalans.len() - 1;
+                let product = self.catalans[m].mul_small(2 * (2 * m as u32 + 1));
+                let next = product.div_rem_small(m as u32 + 2).map(|(q, _)| q).unwrap_or_default();
+                self.catalans.push(next);
+            }
+            self.catalans[n].clone()
+        }
+
+        // p(n, k) = p(n - 1, k - 1) + p(n - k, k)
+        pub fn partitions(&mut self, n: usize, k: usize) -> BigUint {
+            extend_triangle(&mut self.partitions, n, |t, m, k| &entry(t, m - 1, k - 1) + &entry(t, m - k, k));
+            entry(&self.partitions, n, k)
+        }
+
+        // 拆成至多 k 个部分
+        pub fn partitions_at_most(&mut self, n: usize, k: usize) -> BigUint {
+            (0..=k.min(n)).map(|i| self.partitions(n, i)).sum()
+        }
+
+        // 整数拆分数 p(n)
+        pub fn partition_count(&mut self, n: usize) -> BigUint {
+            self.partitions_at_most(n, n)
+        }
+
+        // 十二种方式: n 个球放入 k 个盒子
+        pub fn twelvefold(&mut self, balls: Balls, boxes: Boxes, rule: Rule, n: usize, k: usize) -> BigUint {
+            let indicator = |b: bool| if b { BigUint::one() } else { BigUint::zero() };
+            match (balls, boxes, rule) {
+                (Balls::Distinct, Boxes::Distinct, Rule::Any) => BigUint::from(k as u64).pow(n as u32),
+                (Balls::Distinct, Boxes::Distinct, Rule::AtMostOne) => self.falling_factorial(k, n),
+                (Balls::Distinct, Boxes::Distinct, Rule::AtLeastOne) => &self.factorial(k) * &self.stirling_second(n, k),
+                (Balls::Distinct, Boxes::Identical, Rule::Any) => (0..=k.min(n)).map(|i| self.stirling_second(n, i)).sum(),
+                (Balls::Distinct, Boxes::Identical, Rule::AtMostOne) => indicator(n <= k),
+                (Balls::Distinct, Boxes::Identical, Rule::AtLeastOne) => self.stirling_second(n, k),
+                // 隔板法
+                (Balls::Identical, Boxes::Distinct, Rule::Any) => match (n + k).checked_sub(1) {
+                    Some(total) => self.binomial(total, n),
+                    None => BigUint::one(),
+                },
+                (Balls::Identical, Boxes::Distinct, Rule::AtMostOne) => self.binomial(k, n),
+                (Balls::Identical, Boxes::Distinct, Rule::AtLeastOne) => match (n.checked_sub(1), k.checked_sub(1)) {
+                    (Some(a), Some(b)) => self.binomial(a, b),
+                    _ => indicator(n == k),
+                },
+                (Balls::Identical, Boxes::Identical, Rule::Any) => self.partitions_at_most(n, k),
+                (Balls::Identical, Boxes::Identical, Rule::AtMostOne) => indicator(n <= k),
+                (Balls::Identical, Boxes::Identical, Rule::AtLeastOne) => self.partitions(n, k),
+            }
+        }
+
+        pub fn twelvefold_table(&mut self, n: usize, k: usize) -> TwelvefoldTable {
+            let entries = BALLS
+                .iter()
+                .map(|&b| {
+                    BOXES
+                        .iter()
+                        .map(|&x| RULES.iter().map(|&r| self.twelvefold(b, x, r, n, k)).collect())
+                        .collect()
+                })
+                .collect();
+            TwelvefoldTable { balls: n, boxes: k, entries }
+        }
+    }
+}
@@ -53,6 +53,7 @@ pub mod geometry {
             Vec3 { x, y, z }
         }
 
+        #[allow(clippy::should_implement_trait)]
         pub fn add(self, other: Vec3) -> Vec3 {
             Vec3 {
                 x: self.x + other.x,
@@ -295,16 +296,16 @@ pub mod geometry {
     }
 
     pub fn three_cosine_theorem(angle_oab: f64, angle_bac: f64) -> Result<f64, GeometryError> {
-        if angle_oab < 0.0 || angle_oab > std::f64::consts::PI / 2.0 ||
-            angle_bac < 0.0 || angle_bac > std::f64::consts::PI / 2.0 {
+        if !(0.0..=std::f64::consts::PI / 2.0).contains(&angle_oab) ||
+            !(0.0..=std::f64::consts::PI / 2.0).contains(&angle_bac) {
             return Err(GeometryError::InvalidParam);
         }
         Ok(angle_oab.cos() * angle_bac.cos())
     }
 
     pub fn three_sine_theorem(angle_oac: f64, angle_aoc: f64) -> Result<f64, GeometryError> {
-        if angle_oac < 0.0 || angle_oac > std::f64::consts::PI / 2.0 ||
-            angle_aoc < 0.0 || angle_aoc > std::f64::consts::PI / 2.0 {
+        if !(0.0..=std::f64::consts::PI / 2.0).contains(&angle_oac) ||
+            !(0.0..=std::f64::consts::PI / 2.0).contains(&angle_aoc) {
             return Err(GeometryError::InvalidParam);
         }
         Ok(angle_oac.sin() * angle_aoc.sin())
@@ -41,7 +41,7 @@ pub mod geometry {
                 TriangleError::AngleOutRange => write!(f, "三角形不存在 [angleOutRange]"),
                 TriangleError::AngleFault => write!(f, "三角形不存在 [angleTotal!=Pi]"),
                 TriangleError::CalibrationFail => write!(f, "计算结果不一 [present!=previous]"),
-                TriangleError::CalculateFail => write!(f, "无法计算     {!}"),
+                TriangleError::CalculateFail => write!(f, "无法计算     {{!}}"),
             }
         }
     }
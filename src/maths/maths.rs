@@ -4,3 +4,60 @@
  * Created: 08/13/2025
  */
 
+pub mod algebra {
+    pub mod comb;
+    pub mod complex;
+    pub mod formulas;
+    pub mod fraction;
+    pub mod identify;
+    pub mod logexp;
+    pub mod polynomial;
+    pub mod sequence;
+}
+
+pub mod analytic {
+    pub mod conic;
+    pub mod fixed;
+    pub mod line;
+    pub mod polar;
+}
+
+pub mod calculus {
+    pub mod derivatives;
+    pub mod inequality;
+    pub mod limits;
+    pub mod parametric;
+}
+
+pub mod discrete {
+    pub mod arrangement;
+    pub mod biguint;
+    pub mod counting;
+    pub mod generators;
+    pub mod special;
+}
+
+pub mod geometry {
+    pub mod solid;
+    pub mod space;
+    pub mod triangle;
+    pub mod trig;
+}
+
+pub mod probability {
+    pub mod contingency;
+    pub mod distribution;
+    pub mod markov;
+    pub mod probability;
+    pub mod random;
+    pub mod regression;
+    pub mod sample_space;
+    pub mod sampling;
+    pub mod simulation;
+    pub mod stats;
+    pub mod variable;
+}
+
+pub mod vector {
+    pub mod vector;
+}
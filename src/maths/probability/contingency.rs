@@ -0,0 +1,184 @@
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+pub mod contingency_tables {
+    use crate::maths::probability::distribution::distributions::chi_squared_cdf;
+    use crate::maths::probability::regression::statistics::chi_squared;
+    use std::fmt;
+
+    const ERR_TABLE_SHAPE: &str = "列联表至少为2行2列, 且各行长度相同";
+    const ERR_NEGATIVE_COUNT: &str = "频数不能为负数";
+    const ERR_EMPTY_MARGIN: &str = "列联表的行和与列和都必须为正";
+    const ERR_INVALID_DF: &str = "自由度必须为正整数";
+    const ERR_INVALID_ALPHA: &str = "显著性水平α必须在(0, 1)内";
+
+    pub const ALPHA_LEVELS: [f64; 5] = [0.1, 0.05, 0.01, 0.005, 0.001];
+
+    // χ² 分布临界值表, 第 df - 1 行对应自由度 df, 列与 ALPHA_LEVELS 对应
+    const CRITICAL_VALUES: [[f64; 5]; 10] = [
+        [2.706, 3.841, 6.635, 7.879, 10.828],
+        [4.605, 5.991, 9.210, 10.597, 13.816],
+        [6.251, 7.815, 11.345, 12.838, 16.266],
+        [7.779, 9.488, 13.277, 14.860, 18.467],
+        [9.236, 11.070, 15.086, 16.750, 20.515],
+        [10.645, 12.592, 16.812, 18.548, 22.458],
+        [12.017, 14.067, 18.475, 20.278, 24.322],
+        [13.362, 15.507, 20.090, 21.955, 26.124],
+        [14.684, 16.919, 21.666, 23.589, 27.877],
+        [15.987, 18.307, 23.209, 25.188, 29.588],
+    ];
+    const BISECTION_STEPS: usize = 200;
+
+    // 表内的自由度与α查表, 其余情形对 χ² 分布函数二分求上侧分位数
+    pub fn critical_value(df: usize, alpha: f64) -> Result<f64, &'static str> {
+        if df == 0 {
+            return Err(ERR_INVALID_DF);
+        }
+        if alpha <= 0.0 || alpha >= 1.0 {
+            return Err(ERR_INVALID_ALPHA);
+        }
+        let column = ALPHA_LEVELS.iter().position(|&a| (a - alpha).abs() < 1e-12);
+        if let Some(column) = column.filter(|_| df <= CRITICAL_VALUES.len()) {
+            return Ok(CRITICAL_VALUES[df - 1][column]);
+        }
+        let (mut lo, mut hi) = (0.0, df as f64 + 10.0 * (2.0 * df as f64).sqrt() + 50.0);
+        for _ in 0..BISECTION_STEPS {
+            let mid = (lo + hi) / 2.0;
+            if 1.0 - chi_squared_cdf(mid, df) > alpha {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        Ok((lo + hi) / 2.0)
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Conclusion {
+        pub alpha: f64,
+        pub critical_value: f64,
+        // K² >= 临界值时推断 H0 不成立, 即两个分类变量有关联
+        pub associated: bool,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct IndependenceTest {
+        pub statistic: f64,
+        pub df: usize,
+        pub p_value: f64,
+        pub conclusions: Vec<Conclusion>,
+    }
+
+    impl IndependenceTest {
+        // 能推断有关联的最小α, 都不能推断时为 None
+        pub fn strongest(&self) -> Option<Conclusion> {
+            self.conclusions.iter().filter(|c| c.associated).min_by(|a, b| a.alpha.total_cmp(&b.alpha)).copied()
+        }
+    }
+
+    impl fmt::Display for IndependenceTest {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            writeln!(f, "χ² = {:.3}, 自由度 = {}, p = {:.4}", self.statistic, self.df, self.p_value)?;
+            match self.strongest() {
+                Some(c) => write!(
+                    f,
+                    "根据小概率值α = {}的独立性检验, 推断H0不成立, 即认为两个分类变量有关联, 此推断犯错误的概率不大于{}",
+                    c.alpha, c.alpha
+                ),
+                None => write!(f, "根据小概率值α = {}的独立性检验, 没有充分证据推断H0不成立, 可以认为两个分类变量独立", ALPHA_LEVELS[0]),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ContingencyTable {
+        counts: Vec<Vec<f64>>,
+    }
+
+    impl ContingencyTable {
+        pub fn new(counts: Vec<Vec<f64>>) -> Result<ContingencyTable, &'static str> {
+            if counts.len() < 2 || counts[0].len() < 2 || counts.iter().any(|row| row.len() != counts[0].len()) {
+                return Err(ERR_TABLE_SHAPE);
+            }
+            if counts.iter().flatten().any(|&x| x < 0.0) {
+                return Err(ERR_NEGATIVE_COUNT);
+            }
+            let table = ContingencyTable { counts };
+            if table.row_totals().iter().chain(&table.column_totals()).any(|&t| t <= 0.0) {
+                return Err(ERR_EMPTY_MARGIN);
+            }
+            Ok(table)
+        }
+
+        // | a b |
+        // | c d |
+        pub fn two_by_two(a: f64, b: f64, c: f64, d: f64) -> Result<ContingencyTable, &'static str> {
+            ContingencyTable::new(vec![vec![a, b], vec![c, d]])
+        }
+
+        pub fn counts(&self) -> &[Vec<f64>] {
+            &self.counts
+        }
+
+        pub fn row_totals(&self) -> Vec<f64> {
+            self.counts.iter().map(|row| row.iter().sum()).collect()
+        }
+
+        pub fn column_totals(&self) -> Vec<f64> {
+            (0..self.counts[0].len()).map(|j| self.counts.iter().map(|row| row[j]).sum()).collect()
+        }
+
+        pub fn total(&self) -> f64 {
+            self.counts.iter().flatten().sum()
+        }
+
+        // 独立假设下的期望频数 e_ij = 行和 × 列和 / n
+        pub fn expected(&self) -> Vec<Vec<f64>> {
+            let n = self.total();
+            let columns = self.column_totals();
+            self.row_totals().iter().map(|&r| columns.iter().map(|&c| r * c / n).collect()).collect()
+        }
+
+        pub fn degrees_of_freedom(&self) -> usize {
+            (self.counts.len() - 1) * (self.counts[0].len() - 1)
+        }
+
+        // 2×2 表用 K² = n(ad - bc)² / ((a+b)(c+d)(a+c)(b+d)), 一般情形为 Σ(o - e)² / e
+        pub fn statistic(&self) -> Result<f64, &'static str> {
+            if self.degrees_of_freedom() == 1 {
+                let (a, b, c, d) = (self.counts[0][0], self.counts[0][1], self.counts[1][0], self.counts[1][1]);
+                let n = a + b + c + d;
+                return Ok(n * (a * d - b * c).powi(2) / ((a + b) * (c + d) * (a + c) * (b + d)));
+            }
+            let observed: Vec<f64> = self.counts.iter().flatten().copied().collect();
+            let expected: Vec<f64> = self.expected().into_iter().flatten().collect();
+            chi_squared(&observed, &expected)
+        }
+
+        pub fn p_value(&self) -> Result<f64, &'static str> {
+            Ok(1.0 - chi_squared_cdf(self.statistic()?, self.degrees_of_freedom()))
+        }
+
+        pub fn conclude(&self, alpha: f64) -> Result<Conclusion, &'static str> {
+            let critical_value = critical_value(self.degrees_of_freedom(), alpha)?;
+            Ok(Conclusion {
+                alpha,
+                critical_value,
+                associated: self.statistic()? >= critical_value,
+            })
+        }
+
+        pub fn independence_test(&self) -> Result<IndependenceTest, &'static str> {
+            let conclusions = ALPHA_LEVELS.iter().map(|&alpha| self.conclude(alpha)).collect::<Result<Vec<_>, _>>()?;
+            Ok(IndependenceTest {
+                statistic: self.statistic()?,
+                df: self.degrees_of_freedom(),
+                p_value: self.p_value()?,
+                conclusions,
+            })
+        }
+    }
+}
@@ -0,0 +1,595 @@
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+pub mod distributions {
+    use std::f64::consts::PI;
+
+    const ERR_INVALID_PROBABILITY: &str = "概率p必须在[0, 1]范围内";
+    const ERR_INVALID_POPULATION: &str = "超几何分布需满足N >= 1, M <= N且n <= N";
+    const ERR_INVALID_RATE: &str = "泊松分布的参数λ必须为正数";
+    const ERR_INVALID_SIGMA: &str = "正态分布的标准差σ必须为正数";
+    const ERR_INVALID_LEVEL: &str = "分位数水平必须在[0, 1]范围内";
+    const ERR_OPEN_LEVEL: &str = "正态分布的分位数水平必须在(0, 1)内";
+    const ERR_INVALID_INTERVAL: &str = "区间需满足a <= b";
+    const ERR_INVALID_DF: &str = "自由度必须为正整数";
+    const ERR_OPEN_LEVEL_T: &str = "t分布的分位数水平必须在(0, 1)内";
+
+    // 无穷支撑的分布列在尾部概率小于该值处截断, 余下概率并入最后一项
+    const TAIL_TOLERANCE: f64 = 1e-12;
+    const SERIES_LIMIT: f64 = 3.0;
+    const SERIES_TERMS: usize = 200;
+    const FRACTION_TERMS: usize = 300;
+    const TINY: f64 = 1e-300;
+    const BISECTION_STEPS: usize = 200;
+
+    const LANCZOS_G: f64 = 7.0;
+    const LANCZOS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    // ln Γ(x), Lanczos 近似, x < 0.5 时用反射公式
+    pub fn ln_gamma(x: f64) -> f64 {
+        if x < 0.5 {
+            return (PI / (PI * x).sin()).abs().ln() - ln_gamma(1.0 - x);
+        }
+        let x = x - 1.0;
+        let t = x + LANCZOS_G + 0.5;
+        let series = LANCZOS.iter().enumerate().skip(1).fold(LANCZOS[0], |acc, (i, &c)| acc + c / (x + i as f64));
+        0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+    }
+
+    fn ln_combination(n: u64, k: u64) -> f64 {
+        ln_gamma(n as f64 + 1.0) - ln_gamma(k as f64 + 1.0) - ln_gamma((n - k) as f64 + 1.0)
+    }
+
+    // |x| < 3 时用无抵消的级数 erf(x) = 2/√π·e^(-x²)·Σ 2^n x^(2n+1) / (2n+1)!!
+    fn erf_series(x: f64) -> f64 {
+        let mut term = x;
+        let mut sum = x;
+        for n in 1..SERIES_TERMS {
+            term *= 2.0 * x * x / (2 * n + 1) as f64;
+            sum += term;
+            if term.abs() < sum.abs() * f64::EPSILON {
+                break;
+            }
+        }
+        2.0 / PI.sqrt() * (-x * x).exp() * sum
+    }
+
+    // x >= 3 时用 erfc 的连分数展开, Lentz 算法求值
+    fn erfc_fraction(x: f64) -> f64 {
+        let mut f = x;
+        let mut c = x;
+        let mut d = 0.0;
+        for n in 1..FRACTION_TERMS {
+            let a = n as f64 / 2.0;
+            d = x + a * d;
+            d = if d.abs() < TINY { 1.0 / TINY } else { 1.0 / d };
+            c = x + a / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            let delta = c * d;
+            f *= delta;
+            if (delta - 1.0).abs() < f64::EPSILON {
+                break;
+            }
+        }
+        (-x * x).exp() / (f * PI.sqrt())
+    }
+
+    pub fn erf(x: f64) -> f64 {
+        if x.abs() < SERIES_LIMIT {
+            erf_series(x)
+        } else {
+            x.signum() * (1.0 - erfc_fraction(x.abs()))
+        }
+    }
+
+    pub fn erfc(x: f64) -> f64 {
+        if x >= SERIES_LIMIT {
+            erfc_fraction(x)
+        } else if x <= -SERIES_LIMIT {
+            2.0 - erfc_fraction(-x)
+        } else {
+            1.0 - erf_series(x)
+        }
+    }
+
+    // 正则化下不完全伽马函数 P(a, x): x < a + 1 时用级数, 否则用连分数求 Q = 1 - P
+    pub fn regularized_gamma(a: f64, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        let prefix = (a * x.ln() - x - ln_gamma(a)).exp();
+        if x < a + 1.0 {
+            let mut term = 1.0 / a;
+            let mut sum = term;
+            for n in 1..FRACTION_TERMS {
+                term *= x / (a + n as f64);
+                sum += term;
+                if term.abs() < sum.abs() * f64::EPSILON {
+                    break;
+                }
+            }
+            return (prefix * sum).min(1.0);
+        }
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / TINY;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for n in 1..FRACTION_TERMS {
+            let an = -(n as f64) * (n as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            d = if d.abs() < TINY { 1.0 / TINY } else { 1.0 / d };
+            c = b + an / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            let delta = c * d;
+            h *= delta;
+            if (delta - 1.0).abs() < f64::EPSILON {
+                break;
+            }
+        }
+        (1.0 - prefix * h).max(0.0)
+    }
+
+    // 自由度为 df 的 χ² 分布: P(χ² <= x) = P(df/2, x/2)
+    pub fn chi_squared_cdf(x: f64, df: usize) -> f64 {
+        regularized_gamma(df as f64 / 2.0, x / 2.0)
+    }
+
+    // 正则化不完全贝塔函数 I_x(a, b), 用 Lentz 连分式, x 较大时利用 I_x(a, b) = 1 - I_(1-x)(b, a)
+    pub fn regularized_beta(a: f64, b: f64, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        if x >= 1.0 {
+            return 1.0;
+        }
+        if x > (a + 1.0) / (a + b + 2.0) {
+            return 1.0 - regularized_beta(b, a, 1.0 - x);
+        }
+        let prefix = (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+        let mut c = 1.0;
+        let mut d = 1.0 - (a + b) * x / (a + 1.0);
+        d = if d.abs() < TINY { 1.0 / TINY } else { 1.0 / d };
+        let mut h = d;
+        for m in 1..FRACTION_TERMS {
+            let m = m as f64;
+            let even = m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m));
+            let odd = -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0));
+            for an in [even, odd] {
+                d = 1.0 + an * d;
+                d = if d.abs() < TINY { 1.0 / TINY } else { 1.0 / d };
+                c = 1.0 + an / c;
+                if c.abs() < TINY {
+                    c = TINY;
+                }
+                h *= c * d;
+            }
+            if (c * d - 1.0).abs() < f64::EPSILON {
+                break;
+            }
+        }
+        (prefix * h / a).clamp(0.0, 1.0)
+    }
+
+    // 自由度为 df 的 t 分布的分布函数
+    pub fn student_t_cdf(t: f64, df: usize) -> f64 {
+        let v = df as f64;
+        let tail = regularized_beta(v / 2.0, 0.5, v / (v + t * t)) / 2.0;
+        if t >= 0.0 {
+            1.0 - tail
+        } else {
+            tail
+        }
+    }
+
+    pub fn student_t_quantile(q: f64, df: usize) -> Result<f64, &'static str> {
+        if df == 0 {
+            return Err(ERR_INVALID_DF);
+        }
+        if q <= 0.0 || q >= 1.0 {
+            return Err(ERR_OPEN_LEVEL_T);
+        }
+        // 先用正态分位数夹出区间, 再二分
+        let z = standard_normal_quantile(q)?;
+        let (mut lo, mut hi) = (-1.0 - 2.0 * z.abs(), 1.0 + 2.0 * z.abs());
+        while student_t_cdf(lo, df) > q {
+            lo *= 2.0;
+        }
+        while student_t_cdf(hi, df) < q {
+            hi *= 2.0;
+        }
+        for _ in 0..BISECTION_STEPS {
+            let mid = (lo + hi) / 2.0;
+            if student_t_cdf(mid, df) < q {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        Ok((lo + hi) / 2.0)
+    }
+
+    pub fn standard_normal_cdf(z: f64) -> f64 {
+        0.5 * erfc(-z / std::f64::consts::SQRT_2)
+    }
+
+    // Acklam 有理逼近给出初值, 再做两步 Newton 修正
+    pub fn standard_normal_quantile(q: f64) -> Result<f64, &'static str> {
+        if q <= 0.0 || q >= 1.0 {
+            return Err(ERR_OPEN_LEVEL);
+        }
+        const A: [f64; 6] = [-3.969683028665376e1, 2.209460984245205e2, -2.759285104469687e2, 1.38357751867269e2, -3.066479806614716e1, 2.506628277459239];
+        const B: [f64; 5] = [-5.447609879822406e1, 1.615858368580409e2, -1.556989798598866e2, 6.680131188771972e1, -1.328068155288572e1];
+        const C: [f64; 6] = [-7.784894002430293e-3, -3.223964580411365e-1, -2.400758277161838, -2.549732539343734, 4.374664141464968, 2.938163982698783];
+        const D: [f64; 4] = [7.784695709041462e-3, 3.224671290700398e-1, 2.445134137142996, 3.754408661907416];
+        const LOW: f64 = 0.02425;
+
+        let tail = |r: f64| {
+            let s = (-2.0 * r.ln()).sqrt();
+            (((((C[0] * s + C[1]) * s + C[2]) * s + C[3]) * s + C[4]) * s + C[5]) / ((((D[0] * s + D[1]) * s + D[2]) * s + D[3]) * s + 1.0)
+        };
+        let mut z = if q < LOW {
+            tail(q)
+        } else if q > 1.0 - LOW {
+            -tail(1.0 - q)
+        } else {
+            let r = q - 0.5;
+            let s = r * r;
+            (((((A[0] * s + A[1]) * s + A[2]) * s + A[3]) * s + A[4]) * s + A[5]) * r / (((((B[0] * s + B[1]) * s + B[2]) * s + B[3]) * s + B[4]) * s + 1.0)
+        };
+        for _ in 0..2 {
+            let density = (-z * z / 2.0).exp() / (2.0 * PI).sqrt();
+            z -= (standard_normal_cdf(z) - q) / density;
+        }
+        Ok(z)
+    }
+
+    // 取值为非负整数的离散型分布; support 的上界为 None 表示无穷
+    pub trait DiscreteDistribution {
+        fn pmf(&self, k: u64) -> f64;
+        fn support(&self) -> (u64, Option<u64>);
+        fn mean(&self) -> f64;
+        fn variance(&self) -> f64;
+        fn mode(&self) -> Vec<u64>;
+
+        fn std_dev(&self) -> f64 {
+            self.variance().sqrt()
+        }
+
+        fn cdf(&self, k: u64) -> f64 {
+            let (lo, hi) = self.support();
+            if k < lo {
+                return 0.0;
+            }
+            let k = hi.map_or(k, |hi| k.min(hi));
+            (lo..=k).map(|i| self.pmf(i)).sum::<f64>().min(1.0)
+        }
+
+        // 使 P(X <= k) >= q 的最小 k
+        fn quantile(&self, q: f64) -> Result<u64, &'static str> {
+            if !(0.0..=1.0).contains(&q) {
+                return Err(ERR_INVALID_LEVEL);
+            }
+            let (lo, hi) = self.support();
+            let mut cumulative = 0.0;
+            let mut k = lo;
+            loop {
+                cumulative += self.pmf(k);
+                if cumulative >= q - TAIL_TOLERANCE || hi == Some(k) || 1.0 - cumulative < TAIL_TOLERANCE {
+                    return Ok(k);
+                }
+                k += 1;
+            }
+        }
+
+        // 转为 expected_value、variance 所接受的 (取值, 概率) 形式
+        fn to_table(&self) -> (Vec<f64>, Vec<f64>) {
+            let (lo, hi) = self.support();
+            let (mut values, mut probabilities) = (Vec::new(), Vec::new());
+            let mut cumulative = 0.0;
+            let mut k = lo;
+            loop {
+                let p = self.pmf(k);
+                values.push(k as f64);
+                probabilities.push(p);
+                cumulative += p;
+                if hi == Some(k) || (hi.is_none() && 1.0 - cumulative < TAIL_TOLERANCE && k as f64 > self.mean()) {
+                    break;
+                }
+                k += 1;
+            }
+            if let Some(last) = probabilities.last_mut() {
+                *last = (*last + 1.0 - cumulative).max(0.0);
+            }
+            (values, probabilities)
+        }
+    }
+
+    fn check_probability(p: f64) -> Result<(), &'static str> {
+        if (0.0..=1.0).contains(&p) {
+            Ok(())
+        } else {
+            Err(ERR_INVALID_PROBABILITY)
+        }
+    }
+
+    // X ~ B(n, p)
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Binomial {
+        pub n: u64,
+        pub p: f64,
+    }
+
+    impl Binomial {
+        pub fn new(n: u64, p: f64) -> Result<Binomial, &'static str> {
+            check_probability(p)?;
+            Ok(Binomial { n, p })
+        }
+    }
+
+    impl DiscreteDistribution for Binomial {
+        fn pmf(&self, k: u64) -> f64 {
+            if k > self.n {
+                return 0.0;
+            }
+            if self.p == 0.0 || self.p == 1.0 {
+                let certain = if self.p == 0.0 { 0 } else { self.n };
+                return if k == certain { 1.0 } else { 0.0 };
+            }
+            (ln_combination(self.n, k) + k as f64 * self.p.ln() + (self.n - k) as f64 * (1.0 - self.p).ln()).exp()
+        }
+
+        fn support(&self) -> (u64, Option<u64>) {
+            (0, Some(self.n))
+        }
+
+        fn mean(&self) -> f64 {
+            self.n as f64 * self.p
+        }
+
+        fn variance(&self) -> f64 {
+            self.n as f64 * self.p * (1.0 - self.p)
+        }
+
+        // (n+1)p 为不超过 n 的正整数时有两个众数; p = 1 时 (n+1)p = n+1, 众数只有 n
+        fn mode(&self) -> Vec<u64> {
+            let m = (self.n + 1) as f64 * self.p;
+            if m == m.floor() && m >= 1.0 && m <= self.n as f64 {
+                vec![m as u64 - 1, m as u64]
+            } else {
+                vec![(m.floor() as u64).min(self.n)]
+            }
+        }
+    }
+
+    // 从含 m 件次品的 N 件产品中不放回地抽取 n 件, X 为其中的次品数
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Hypergeometric {
+        pub population: u64,
+        pub successes: u64,
+        pub draws: u64,
+    }
+
+    impl Hypergeometric {
+        pub fn new(population: u64, successes: u64, draws: u64) -> Result<Hypergeometric, &'static str> {
+            if population == 0 || successes > population || draws > population {
+                return Err(ERR_INVALID_POPULATION);
+            }
+            Ok(Hypergeometric { population, successes, draws })
+        }
+    }
+
+    impl DiscreteDistribution for Hypergeometric {
+        fn pmf(&self, k: u64) -> f64 {
+            let (lo, hi) = self.support();
+            if k < lo || k > hi.unwrap_or(0) {
+                return 0.0;
+            }
+            let (n, m, r) = (self.population, self.successes, self.draws);
+            (ln_combination(m, k) + ln_combination(n - m, r - k) - ln_combination(n, r)).exp()
+        }
+
+        fn support(&self) -> (u64, Option<u64>) {
+            let failures = self.population - self.successes;
+            (self.draws.saturating_sub(failures), Some(self.draws.min(self.successes)))
+        }
+
+        fn mean(&self) -> f64 {
+            self.draws as f64 * self.successes as f64 / self.population as f64
+        }
+
+        fn variance(&self) -> f64 {
+            // N = 1 时 X 为常数, 有限总体校正因子的分母 N - 1 为 0
+            if self.population == 1 {
+                return 0.0;
+            }
+            let (n, m, r) = (self.population as f64, self.successes as f64, self.draws as f64);
+            r * (m / n) * (1.0 - m / n) * (n - r) / (n - 1.0)
+        }
+
+        fn mode(&self) -> Vec<u64> {
+            let (n, m, r) = (self.population, self.successes, self.draws);
+            let numerator = (r + 1) * (m + 1);
+            let k = numerator / (n + 2);
+            if numerator % (n + 2) == 0 && k > self.support().0 {
+                vec![k - 1, k]
+            } else {
+                vec![k]
+            }
+        }
+    }
+
+    // 独立重复试验中首次成功所需的试验次数, 取值 1, 2, ...
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Geometric {
+        pub p: f64,
+    }
+
+    impl Geometric {
+        pub fn new(p: f64) -> Result<Geometric, &'static str> {
+            if p <= 0.0 || p > 1.0 {
+                return Err(ERR_INVALID_PROBABILITY);
+            }
+            Ok(Geometric { p })
+        }
+    }
+
+    impl DiscreteDistribution for Geometric {
+        fn pmf(&self, k: u64) -> f64 {
+            if k == 0 {
+                return 0.0;
+            }
+            (1.0 - self.p).powf((k - 1) as f64) * self.p
+        }
+
+        fn support(&self) -> (u64, Option<u64>) {
+            (1, if self.p == 1.0 { Some(1) } else { None })
+        }
+
+        fn cdf(&self, k: u64) -> f64 {
+            1.0 - (1.0 - self.p).powf(k as f64)
+        }
+
+        fn mean(&self) -> f64 {
+            1.0 / self.p
+        }
+
+        fn variance(&self) -> f64 {
+            (1.0 - self.p) / (self.p * self.p)
+        }
+
+        fn mode(&self) -> Vec<u64> {
+            vec![1]
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Poisson {
+        pub lambda: f64,
+    }
+
+    impl Poisson {
+        pub fn new(lambda: f64) -> Result<Poisson, &'static str> {
+            if lambda <= 0.0 || !lambda.is_finite() {
+                return Err(ERR_INVALID_RATE);
+            }
+            Ok(Poisson { lambda })
+        }
+    }
+
+    impl DiscreteDistribution for Poisson {
+        fn pmf(&self, k: u64) -> f64 {
+            (k as f64 * self.lambda.ln() - self.lambda - ln_gamma(k as f64 + 1.0)).exp()
+        }
+
+        fn support(&self) -> (u64, Option<u64>) {
+            (0, None)
+        }
+
+        fn mean(&self) -> f64 {
+            self.lambda
+        }
+
+        fn variance(&self) -> f64 {
+            self.lambda
+        }
+
+        // λ 为整数时 λ-1 与 λ 均为众数
+        fn mode(&self) -> Vec<u64> {
+            let k = self.lambda.floor() as u64;
+            if self.lambda == self.lambda.floor() {
+                vec![k - 1, k]
+            } else {
+                vec![k]
+            }
+        }
+    }
+
+    // X ~ N(μ, σ²)
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Normal {
+        pub mu: f64,
+        pub sigma: f64,
+    }
+
+    impl Normal {
+        pub fn new(mu: f64, sigma: f64) -> Result<Normal, &'static str> {
+            if sigma <= 0.0 || !sigma.is_finite() {
+                return Err(ERR_INVALID_SIGMA);
+            }
+            Ok(Normal { mu, sigma })
+        }
+
+        pub fn standard() -> Normal {
+            Normal { mu: 0.0, sigma: 1.0 }
+        }
+
+        pub fn pdf(&self, x: f64) -> f64 {
+            let z = self.standardize(x);
+            (-z * z / 2.0).exp() / (self.sigma * (2.0 * PI).sqrt())
+        }
+
+        pub fn cdf(&self, x: f64) -> f64 {
+            standard_normal_cdf(self.standardize(x))
+        }
+
+        pub fn mean(&self) -> f64 {
+            self.mu
+        }
+
+        pub fn variance(&self) -> f64 {
+            self.sigma * self.sigma
+        }
+
+        pub fn mode(&self) -> f64 {
+            self.mu
+        }
+
+        pub fn quantile(&self, q: f64) -> Result<f64, &'static str> {
+            Ok(self.mu + self.sigma * standard_normal_quantile(q)?)
+        }
+
+        pub fn standardize(&self, x: f64) -> f64 {
+            (x - self.mu) / self.sigma
+        }
+
+        // P(a <= X <= b)
+        pub fn interval_probability(&self, a: f64, b: f64) -> Result<f64, &'static str> {
+            if a > b {
+                return Err(ERR_INVALID_INTERVAL);
+            }
+            Ok(self.cdf(b) - self.cdf(a))
+        }
+
+        // (μ - kσ, μ + kσ)
+        pub fn sigma_interval(&self, k: f64) -> (f64, f64) {
+            (self.mu - k * self.sigma, self.mu + k * self.sigma)
+        }
+
+        pub fn sigma_probability(&self, k: f64) -> f64 {
+            erf(k.abs() / std::f64::consts::SQRT_2)
+        }
+
+        // 3σ 原则: 落在 (μ-σ, μ+σ)、(μ-2σ, μ+2σ)、(μ-3σ, μ+3σ) 内的概率约为 0.6827、0.9545、0.9973
+        pub fn three_sigma_rule(&self) -> [((f64, f64), f64); 3] {
+            [1.0, 2.0, 3.0].map(|k| (self.sigma_interval(k), self.sigma_probability(k)))
+        }
+    }
+}
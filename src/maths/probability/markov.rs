@@ -0,0 +1,345 @@
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+pub mod markov_chains {
+    use crate::maths::algebra::fraction::fractions::Fraction;
+    use std::fmt::Debug;
+    use std::ops::{Add, Div, Mul, Sub};
+
+    const ERR_NOT_SQUARE: &str = "转移矩阵必须为非空方阵";
+    const ERR_INVALID_ENTRY: &str = "转移概率必须在[0, 1]范围内";
+    const ERR_ROW_SUM: &str = "转移矩阵每行概率之和必须为1";
+    const ERR_DIMENSION: &str = "分布向量的长度必须与状态数一致";
+    const ERR_NOT_DISTRIBUTION: &str = "初始分布必须非负且总和为1";
+    const ERR_NOT_UNIQUE: &str = "平稳分布不唯一";
+    const ERR_NO_ABSORBING: &str = "不存在吸收态";
+    const ERR_SINGULAR: &str = "存在无法到达目标的状态, 方程组无唯一解";
+    const ERR_INVALID_STATE: &str = "状态编号超出范围";
+    const ERR_INVALID_BARRIERS: &str = "随机游走需满足 lower < upper";
+
+    const F64_TOLERANCE: f64 = 1e-12;
+
+    // f64 与 Fraction 共用的域运算; Fraction 输入时全部结果为精确有理数
+    // 结果可能超出 Fraction 的范围时使用 checked_* 运算, f64 总是返回 Ok
+    pub trait Scalar: Copy + Debug + PartialEq + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> {
+        fn zero() -> Self;
+        fn one() -> Self;
+        fn magnitude(&self) -> f64;
+        fn is_negligible(&self) -> bool;
+        fn checked_add(self, other: Self) -> Result<Self, &'static str>;
+        fn checked_sub(self, other: Self) -> Result<Self, &'static str>;
+        fn checked_mul(self, other: Self) -> Result<Self, &'static str>;
+        fn checked_div(self, other: Self) -> Result<Self, &'static str>;
+    }
+
+    impl Scalar for f64 {
+        fn zero() -> f64 {
+            0.0
+        }
+
+        fn one() -> f64 {
+            1.0
+        }
+
+        fn magnitude(&self) -> f64 {
+            self.abs()
+        }
+
+        fn is_negligible(&self) -> bool {
+            self.abs() < F64_TOLERANCE
+        }
+
+        fn checked_add(self, other: f64) -> Result<f64, &'static str> {
+            Ok(self + other)
+        }
+
+        fn checked_sub(self, other: f64) -> Result<f64, &'static str> {
+            Ok(self - other)
+        }
+
+        fn checked_mul(self, other: f64) -> Result<f64, &'static str> {
+            Ok(self * other)
+        }
+
+        fn checked_div(self, other: f64) -> Result<f64, &'static str> {
+            Ok(self / other)
+        }
+    }
+
+    impl Scalar for Fraction {
+        fn zero() -> Fraction {
+            Fraction::zero()
+        }
+
+        fn one() -> Fraction {
+            Fraction::one()
+        }
+
+        fn magnitude(&self) -> f64 {
+            self.to_f64().abs()
+        }
+
+        fn is_negligible(&self) -> bool {
+            self.is_zero()
+        }
+
+        fn checked_add(self, other: Fraction) -> Result<Fraction, &'static str> {
+            Fraction::checked_add(self, other)
+        }
+
+        fn checked_sub(self, other: Fraction) -> Result<Fraction, &'static str> {
+            Fraction::checked_sub(self, other)
+        }
+
+        fn checked_mul(self, other: Fraction) -> Result<Fraction, &'static str> {
+            Fraction::checked_mul(self, other)
+        }
+
+        fn checked_div(self, other: Fraction) -> Result<Fraction, &'static str> {
+            Fraction::checked_div(self, other)
+        }
+    }
+
+    fn sum<T: Scalar>(values: &[T]) -> Result<T, &'static str> {
+        values.iter().try_fold(T::zero(), |acc, &v| acc.checked_add(v))
+    }
+
+    // 列主元消去法解 A x = b, b 可以有多列
+    fn solve<T: Scalar>(mut a: Vec<Vec<T>>, mut b: Vec<Vec<T>>) -> Result<Vec<Vec<T>>, &'static str> {
+        let n = a.len();
+        for col in 0..n {
+            let pivot = (col..n)
+                .max_by(|&i, &j| a[i][col].magnitude().total_cmp(&a[j][col].magnitude()))
+                .filter(|&i| !a[i][col].is_negligible())
+                .ok_or(ERR_SINGULAR)?;
+            a.swap(col, pivot);
+            b.swap(col, pivot);
+            let (pivot_a, pivot_b) = (a[col].clone(), b[col].clone());
+            for row in 0..n {
+                if row == col || a[row][col].is_negligible() {
+                    continue;
+                }
+                let factor = a[row][col].checked_div(pivot_a[col])?;
+                for (x, &p) in a[row].iter_mut().zip(&pivot_a) {
+                    *x = x.checked_sub(factor.checked_mul(p)?)?;
+                }
+                for (x, &p) in b[row].iter_mut().zip(&pivot_b) {
+                    *x = x.checked_sub(factor.checked_mul(p)?)?;
+                }
+            }
+        }
+        b.into_iter().enumerate().map(|(i, row)| row.into_iter().map(|x| x.checked_div(a[i][i])).collect()).collect()
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Absorption<T: Scalar> {
+        pub transient: Vec<usize>,
+        pub absorbing: Vec<usize>,
+        // probabilities[i][j]: 从第 i 个非吸收态出发, 最终被第 j 个吸收态吸收的概率
+        pub probabilities: Vec<Vec<T>>,
+        pub expected_steps: Vec<T>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MarkovChain<T: Scalar> {
+        matrix: Vec<Vec<T>>,
+    }
+
+    impl<T: Scalar> MarkovChain<T> {
+        pub fn new(matrix: Vec<Vec<T>>) -> Result<MarkovChain<T>, &'static str> {
+            let n = matrix.len();
+            if n == 0 || matrix.iter().any(|row| row.len() != n) {
+                return Err(ERR_NOT_SQUARE);
+            }
+            if matrix.iter().flatten().any(|&p| p < T::zero() || p > T::one()) {
+                return Err(ERR_INVALID_ENTRY);
+            }
+            if matrix.iter().any(|row| !sum(row).and_then(|s| s.checked_sub(T::one())).is_ok_and(|d| d.is_negligible())) {
+                return Err(ERR_ROW_SUM);
+            }
+            Ok(MarkovChain { matrix })
+        }
+
+        pub fn states(&self) -> usize {
+            self.matrix.len()
+        }
+
+        pub fn matrix(&self) -> &[Vec<T>] {
+            &self.matrix
+        }
+
+        fn multiply(a: &[Vec<T>], b: &[Vec<T>]) -> Result<Vec<Vec<T>>, &'static str> {
+            a.iter()
+                .map(|row| {
+                    (0..b[0].len())
+                        .map(|j| row.iter().zip(b).try_fold(T::zero(), |acc, (&x, r)| acc.checked_add(x.checked_mul(r[j])?)))
+                        .collect()
+                })
+                .collect()
+        }
+
+        // 行向量乘转移矩阵: π_(n+1) = π_n·P
+        pub fn step(&self, distribution: &[T]) -> Result<Vec<T>, &'static str> {
+            if distribution.len() != self.states() {
+                return Err(ERR_DIMENSION);
+            }
+            Ok(MarkovChain::multiply(&[distribution.to_vec()], &self.matrix)?.remove(0))
+        }
+
+        // n 步转移矩阵 P^n, 快速幂; 最高位之后不再平方, 以免算出用不到的 P^(2^k)
+        pub fn n_step_matrix(&self, n: u32) -> Result<Vec<Vec<T>>, &'static str> {
+            let size = self.states();
+            let mut result: Vec<Vec<T>> = (0..size).map(|i| (0..size).map(|j| if i == j { T::one() } else { T::zero() }).collect()).collect();
+            let mut base = self.matrix.clone();
+            let mut e = n;
+            while e > 0 {
+                if e & 1 == 1 {
+                    result = MarkovChain::multiply(&result, &base)?;
+                }
+                if e > 1 {
+                    base = MarkovChain::multiply(&base, &base)?;
+                }
+                e >>= 1;
+            }
+            Ok(result)
+        }
+
+        pub fn distribution_after(&self, initial: &[T], n: u32) -> Result<Vec<T>, &'static str> {
+            if initial.len() != self.states() {
+                return Err(ERR_DIMENSION);
+            }
+            if initial.iter().any(|&p| p < T::zero()) || !sum(initial)?.checked_sub(T::one())?.is_negligible() {
+                return Err(ERR_NOT_DISTRIBUTION);
+            }
+            Ok(MarkovChain::multiply(&[initial.to_vec()], &self.n_step_matrix(n)?)?.remove(0))
+        }
+
+        // 解 π(P - I) = 0, Σπ = 1; 以归一化条件替换最后一个方程
+        pub fn stationary(&self) -> Result<Vec<T>, &'static str> {
+            let n = self.states();
+            let mut a: Vec<Vec<T>> = (0..n)
+                .map(|i| (0..n).map(|j| self.matrix[j][i] - if i == j { T::one() } else { T::zero() }).collect())
+                .collect();
+            a[n - 1] = vec![T::one(); n];
+            let mut b = vec![vec![T::zero()]; n];
+            b[n - 1][0] = T::one();
+            let x = solve(a, b).map_err(|_| ERR_NOT_UNIQUE)?;
+            Ok(x.into_iter().map(|row| row[0]).collect())
+        }
+
+        pub fn absorbing_states(&self) -> Vec<usize> {
+            (0..self.states()).filter(|&i| self.matrix[i][i] == T::one()).collect()
+        }
+
+        // 标准型 P = [[Q, R], [0, I]]: 吸收概率 B = (I - Q)⁻¹R, 期望步数 t = (I - Q)⁻¹·1
+        pub fn absorption(&self) -> Result<Absorption<T>, &'static str> {
+            let absorbing = self.absorbing_states();
+            if absorbing.is_empty() {
+                return Err(ERR_NO_ABSORBING);
+            }
+            let transient: Vec<usize> = (0..self.states()).filter(|i| !absorbing.contains(i)).collect();
+            let a: Vec<Vec<T>> = transient
+                .iter()
+                .map(|&i| transient.iter().map(|&j| if i == j { T::one() } else { T::zero() } - self.matrix[i][j]).collect())
+                .collect();
+            let b: Vec<Vec<T>> = transient
+                .iter()
+                .map(|&i| absorbing.iter().map(|&j| self.matrix[i][j]).chain(std::iter::once(T::one())).collect())
+                .collect();
+            let x = solve(a, b)?;
+            let k = absorbing.len();
+            let probabilities = x.iter().map(|row| row[..k].to_vec()).collect();
+            let expected_steps = x.iter().map(|row| row[k]).collect();
+            Ok(Absorption { transient, absorbing, probabilities, expected_steps })
+        }
+
+        // 首次到达 targets 中任一状态的期望步数: h_i = 0 (i ∈ targets), h_i = 1 + Σ P_ij·h_j
+        pub fn expected_hitting_times(&self, targets: &[usize]) -> Result<Vec<T>, &'static str> {
+            let n = self.states();
+            if targets.is_empty() || targets.iter().any(|&t| t >= n) {
+                return Err(ERR_INVALID_STATE);
+            }
+            let others: Vec<usize> = (0..n).filter(|i| !targets.contains(i)).collect();
+            let a: Vec<Vec<T>> = others
+                .iter()
+                .map(|&i| others.iter().map(|&j| if i == j { T::one() } else { T::zero() } - self.matrix[i][j]).collect())
+                .collect();
+            let b = vec![vec![T::one()]; others.len()];
+            let x = if others.is_empty() { Vec::new() } else { solve(a, b)? };
+            let mut times = vec![T::zero(); n];
+            for (&i, row) in others.iter().zip(x) {
+                times[i] = row[0];
+            }
+            Ok(times)
+        }
+    }
+
+    // 一维随机游走: 每步以概率 p 向右移动1, 以概率 1-p 向左移动1
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct RandomWalk<T: Scalar> {
+        pub p: T,
+    }
+
+    impl<T: Scalar> RandomWalk<T> {
+        pub fn new(p: T) -> Result<RandomWalk<T>, &'static str> {
+            if p < T::zero() || p > T::one() {
+                return Err(ERR_INVALID_ENTRY);
+            }
+            Ok(RandomWalk { p })
+        }
+
+        // 从原点出发走 n 步后的位置分布, 按位置升序
+        pub fn position_distribution(&self, n: u32) -> Result<Vec<(i64, T)>, &'static str> {
+            let q = T::one() - self.p;
+            let mut probabilities = vec![T::one()];
+            for _ in 0..n {
+                let mut next = vec![T::zero(); probabilities.len() + 1];
+                for (k, &x) in probabilities.iter().enumerate() {
+                    next[k] = next[k].checked_add(x.checked_mul(q)?)?;
+                    next[k + 1] = next[k + 1].checked_add(x.checked_mul(self.p)?)?;
+                }
+                probabilities = next;
+            }
+            Ok(probabilities.into_iter().enumerate().map(|(k, x)| (2 * k as i64 - n as i64, x)).collect())
+        }
+
+        // 在 lower 与 upper 处设吸收壁, 状态 i 对应位置 lower + i
+        pub fn with_barriers(&self, lower: i64, upper: i64) -> Result<MarkovChain<T>, &'static str> {
+            if lower >= upper {
+                return Err(ERR_INVALID_BARRIERS);
+            }
+            let n = (upper - lower + 1) as usize;
+            let q = T::one() - self.p;
+            let matrix = (0..n)
+                .map(|i| {
+                    let mut row = vec![T::zero(); n];
+                    if i == 0 || i == n - 1 {
+                        row[i] = T::one();
+                    } else {
+                        row[i - 1] = q;
+                        row[i + 1] = self.p;
+                    }
+                    row
+                })
+                .collect();
+            MarkovChain::new(matrix)
+        }
+
+        // 赌徒输光问题: 从 start 出发先到达 lower 的概率与游走的期望步数
+        pub fn ruin(&self, lower: i64, upper: i64, start: i64) -> Result<(T, T), &'static str> {
+            if start < lower || start > upper {
+                return Err(ERR_INVALID_STATE);
+            }
+            if start == lower || start == upper {
+                let ruined = if start == lower { T::one() } else { T::zero() };
+                return Ok((ruined, T::zero()));
+            }
+            let absorption = self.with_barriers(lower, upper)?.absorption()?;
+            let row = absorption.transient.iter().position(|&s| s as i64 == start - lower).ok_or(ERR_INVALID_STATE)?;
+            Ok((absorption.probabilities[row][0], absorption.expected_steps[row]))
+        }
+    }
+}
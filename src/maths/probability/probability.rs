@@ -11,7 +11,7 @@ pub mod probability {
     const ERR_ZERO_PROBABILITY: &str = "条件事件的概率不能为0";
 
     pub fn is_independent(p_a: f64, p_b: f64, p_ab: f64) -> Result<bool, &'static str> {
-        if p_a < 0.0 || p_a > 1.0 || p_b < 0.0 || p_b > 1.0 || p_ab < 0.0 || p_ab > 1.0 {
+        if !(0.0..=1.0).contains(&p_a) || !(0.0..=1.0).contains(&p_b) || !(0.0..=1.0).contains(&p_ab) {
             return Err(ERR_INVALID_PROBABILITY);
         }
         Ok((p_ab - p_a * p_b).abs() < 1e-9)
@@ -28,7 +28,7 @@ pub mod probability {
     }
 
     pub fn conditional_probability(p_ab: f64, p_b: f64) -> Result<f64, &'static str> {
-        if p_ab < 0.0 || p_ab > 1.0 || p_b < 0.0 || p_b > 1.0 {
+        if !(0.0..=1.0).contains(&p_ab) || !(0.0..=1.0).contains(&p_b) {
             return Err(ERR_INVALID_PROBABILITY);
         }
         if p_b < 1e-9 {
@@ -38,7 +38,7 @@ pub mod probability {
     }
 
     pub fn multiplication_rule(p_a: f64, p_b_given_a: f64) -> Result<f64, &'static str> {
-        if p_a < 0.0 || p_a > 1.0 || p_b_given_a < 0.0 || p_b_given_a > 1.0 {
+        if !(0.0..=1.0).contains(&p_a) || !(0.0..=1.0).contains(&p_b_given_a) {
             return Err(ERR_INVALID_PROBABILITY);
         }
         Ok(p_a * p_b_given_a)
@@ -52,12 +52,12 @@ pub mod probability {
             return Err("划分概率与条件概率数量必须相等");
         }
         for &p in partition_probs {
-            if p < 0.0 || p > 1.0 {
+            if !(0.0..=1.0).contains(&p) {
                 return Err(ERR_INVALID_PROBABILITY);
             }
         }
         for &p in conditional_probs {
-            if p < 0.0 || p > 1.0 {
+            if !(0.0..=1.0).contains(&p) {
                 return Err(ERR_INVALID_PROBABILITY);
             }
         }
@@ -69,7 +69,7 @@ pub mod probability {
     }
 
     pub fn bayes_theorem(prior: f64, likelihood: f64, evidence: f64) -> Result<f64, &'static str> {
-        if prior < 0.0 || prior > 1.0 || likelihood < 0.0 || likelihood > 1.0 || evidence < 0.0 || evidence > 1.0 {
+        if !(0.0..=1.0).contains(&prior) || !(0.0..=1.0).contains(&likelihood) || !(0.0..=1.0).contains(&evidence) {
             return Err(ERR_INVALID_PROBABILITY);
         }
         if evidence < 1e-9 {
@@ -19,7 +19,7 @@ pub mod random_variables {
         }
         let mut prob_sum = 0.0;
         for &p in probabilities {
-            if p < 0.0 || p > 1.0 {
+            if !(0.0..=1.0).contains(&p) {
                 return Err(ERR_INVALID_PROBABILITY);
             }
             prob_sum += p;
@@ -5,11 +5,104 @@
  */
 
 pub mod statistics {
+    use crate::maths::probability::distribution::distributions::{student_t_cdf, student_t_quantile};
+    use std::fmt;
+
     const ERR_INSUFFICIENT_DATA: &str = "数据点数量必须至少为2";
     const ERR_MISMATCHED_DATA: &str = "x和y数据点数量必须相等";
     const ERR_ZERO_VARIANCE: &str = "x值不能全相同";
     const ERR_INVALID_OBSERVED: &str = "观测值不能为负数";
     const ERR_INVALID_EXPECTED: &str = "期望值必须为正数";
+    const ERR_ZERO_Y_VARIANCE: &str = "y值不能全相同";
+    const ERR_TOO_FEW_FOR_RESIDUALS: &str = "计算标准化残差至少需要3个数据点";
+    const ERR_NON_POSITIVE_X: &str = "该模型要求x全部为正数";
+    const ERR_NON_POSITIVE_Y: &str = "该模型要求y全部为正数";
+    const ERR_ZERO_X: &str = "该模型要求x不能为0";
+    const ERR_NO_APPLICABLE_MODEL: &str = "没有适用于该数据的回归模型";
+    const ERR_NO_PREDICTORS: &str = "至少需要一个自变量";
+    const ERR_TOO_FEW_OBSERVATIONS: &str = "观测数必须多于待估参数个数";
+    const ERR_COLLINEAR: &str = "自变量之间存在完全共线性";
+    const ERR_PREDICTOR_COUNT: &str = "自变量个数与模型不一致";
+    const ERR_INVALID_CONFIDENCE: &str = "置信水平必须在(0, 1)内";
+    const ERR_PERFECT_FIT: &str = "观测数恰好等于参数个数, 无法估计误差";
+    // Cholesky 主元与原对角元之比 = 该列对前面各列回归的残差平方和 / Σx², 与量纲无关, 过小视为共线
+    const COLLINEAR_TOLERANCE: f64 = 1e-10;
+
+    // 线性化后可用最小二乘拟合的模型
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum RegressionModel {
+        // y = a + b·x
+        Linear,
+        // y = a·e^(b·x), 取 ln y
+        Exponential,
+        // y = a·x^b, 取 ln x 与 ln y
+        Power,
+        // y = a + b·ln x
+        Logarithmic,
+        // y = a + b / x
+        Reciprocal,
+    }
+
+    pub const MODELS: [RegressionModel; 5] = [
+        RegressionModel::Linear,
+        RegressionModel::Exponential,
+        RegressionModel::Power,
+        RegressionModel::Logarithmic,
+        RegressionModel::Reciprocal,
+    ];
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct LinearFit {
+        pub slope: f64,
+        pub intercept: f64,
+        pub r: f64,
+        pub r_squared: f64,
+        pub residuals: Vec<f64>,
+        pub rss: f64,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct StandardizedResidual {
+        pub residual: f64,
+        pub standardized: f64,
+        pub outlier: bool,
+    }
+
+    // R² 与残差平方和均在原始 y 的尺度上计算
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct ModelFit {
+        pub model: RegressionModel,
+        pub a: f64,
+        pub b: f64,
+        pub r_squared: f64,
+        pub rss: f64,
+    }
+
+    impl ModelFit {
+        pub fn predict(&self, x: f64) -> f64 {
+            match self.model {
+                RegressionModel::Linear => self.a + self.b * x,
+                RegressionModel::Exponential => self.a * (self.b * x).exp(),
+                RegressionModel::Power => self.a * x.powf(self.b),
+                RegressionModel::Logarithmic => self.a + self.b * x.ln(),
+                RegressionModel::Reciprocal => self.a + self.b / x,
+            }
+        }
+    }
+
+    impl fmt::Display for ModelFit {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let sign = if self.b < 0.0 { "-" } else { "+" };
+            let (a, b) = (self.a, self.b.abs());
+            match self.model {
+                RegressionModel::Linear => write!(f, "ŷ = {:.4} {} {:.4}x", a, sign, b),
+                RegressionModel::Exponential => write!(f, "ŷ = {:.4}·e^({:.4}x)", a, self.b),
+                RegressionModel::Power => write!(f, "ŷ = {:.4}·x^{:.4}", a, self.b),
+                RegressionModel::Logarithmic => write!(f, "ŷ = {:.4} {} {:.4}ln x", a, sign, b),
+                RegressionModel::Reciprocal => write!(f, "ŷ = {:.4} {} {:.4}/x", a, sign, b),
+            }
+        }
+    }
 
     pub fn least_squares(x: &[f64], y: &[f64]) -> Result<(f64, f64), &'static str> {
         if x.len() < 2 || y.len() < 2 {
@@ -66,4 +159,292 @@ pub mod statistics {
 
         Ok(chi2)
     }
+
+    fn check_pairs(x: &[f64], y: &[f64]) -> Result<(), &'static str> {
+        if x.len() != y.len() {
+            return Err(ERR_MISMATCHED_DATA);
+        }
+        if x.len() < 2 {
+            return Err(ERR_INSUFFICIENT_DATA);
+        }
+        Ok(())
+    }
+
+    fn centered_sums(x: &[f64], y: &[f64]) -> (f64, f64, f64) {
+        let n = x.len() as f64;
+        let (mx, my) = (x.iter().sum::<f64>() / n, y.iter().sum::<f64>() / n);
+        x.iter().zip(y).fold((0.0, 0.0, 0.0), |(sxx, syy, sxy), (&a, &b)| {
+            (sxx + (a - mx).powi(2), syy + (b - my).powi(2), sxy + (a - mx) * (b - my))
+        })
+    }
+
+    // 样本相关系数 r = Σ(x - x̄)(y - ȳ) / √(Σ(x - x̄)²·Σ(y - ȳ)²)
+    pub fn pearson_r(x: &[f64], y: &[f64]) -> Result<f64, &'static str> {
+        check_pairs(x, y)?;
+        let (sxx, syy, sxy) = centered_sums(x, y);
+        if sxx < 1e-12 {
+            return Err(ERR_ZERO_VARIANCE);
+        }
+        if syy < 1e-12 {
+            return Err(ERR_ZERO_Y_VARIANCE);
+        }
+        Ok((sxy / (sxx * syy).sqrt()).clamp(-1.0, 1.0))
+    }
+
+    pub fn residuals(x: &[f64], y: &[f64], slope: f64, intercept: f64) -> Result<Vec<f64>, &'static str> {
+        check_pairs(x, y)?;
+        Ok(x.iter().zip(y).map(|(&a, &b)| b - empirical_regression(a, slope, intercept)).collect())
+    }
+
+    pub fn residual_sum_of_squares(x: &[f64], y: &[f64], slope: f64, intercept: f64) -> Result<f64, &'static str> {
+        Ok(residuals(x, y, slope, intercept)?.iter().map(|e| e * e).sum())
+    }
+
+    // R² = 1 - Σ(y - ŷ)² / Σ(y - ȳ)²
+    pub fn coefficient_of_determination(y: &[f64], fitted: &[f64]) -> Result<f64, &'static str> {
+        check_pairs(y, fitted)?;
+        let mean = y.iter().sum::<f64>() / y.len() as f64;
+        let tss: f64 = y.iter().map(|v| (v - mean).powi(2)).sum();
+        if tss < 1e-12 {
+            return Err(ERR_ZERO_Y_VARIANCE);
+        }
+        let rss: f64 = y.iter().zip(fitted).map(|(a, b)| (a - b).powi(2)).sum();
+        Ok(1.0 - rss / tss)
+    }
+
+    pub fn linear_fit(x: &[f64], y: &[f64]) -> Result<LinearFit, &'static str> {
+        let (slope, intercept) = least_squares(x, y)?;
+        let r = pearson_r(x, y)?;
+        let residuals = residuals(x, y, slope, intercept)?;
+        let rss = residuals.iter().map(|e| e * e).sum();
+        Ok(LinearFit { slope, intercept, r, r_squared: r * r, residuals, rss })
+    }
+
+    // 标准化残差 e_i / σ̂, σ̂² = RSS / (n - 2); 绝对值超过 threshold 的点标记为可疑数据
+    pub fn standardized_residuals(x: &[f64], y: &[f64], threshold: f64) -> Result<Vec<StandardizedResidual>, &'static str> {
+        if x.len() < 3 {
+            return Err(ERR_TOO_FEW_FOR_RESIDUALS);
+        }
+        let fit = linear_fit(x, y)?;
+        let sigma = (fit.rss / (x.len() - 2) as f64).sqrt();
+        Ok(fit
+            .residuals
+            .iter()
+            .map(|&residual| {
+                let standardized = if sigma > 0.0 { residual / sigma } else { 0.0 };
+                StandardizedResidual { residual, standardized, outlier: standardized.abs() > threshold }
+            })
+            .collect())
+    }
+
+    pub fn fit_model(model: RegressionModel, x: &[f64], y: &[f64]) -> Result<ModelFit, &'static str> {
+        check_pairs(x, y)?;
+        let positive = |v: &[f64], err| if v.iter().all(|&t| t > 0.0) { Ok(()) } else { Err(err) };
+        let ln = |v: &[f64]| v.iter().map(|t| t.ln()).collect::<Vec<f64>>();
+        let (a, b) = match model {
+            RegressionModel::Linear => {
+                let (slope, intercept) = least_squares(x, y)?;
+                (intercept, slope)
+            }
+            RegressionModel::Exponential => {
+                positive(y, ERR_NON_POSITIVE_Y)?;
+                let (slope, intercept) = least_squares(x, &ln(y))?;
+                (intercept.exp(), slope)
+            }
+            RegressionModel::Power => {
+                positive(x, ERR_NON_POSITIVE_X)?;
+                positive(y, ERR_NON_POSITIVE_Y)?;
+                let (slope, intercept) = least_squares(&ln(x), &ln(y))?;
+                (intercept.exp(), slope)
+            }
+            RegressionModel::Logarithmic => {
+                positive(x, ERR_NON_POSITIVE_X)?;
+                let (slope, intercept) = least_squares(&ln(x), y)?;
+                (intercept, slope)
+            }
+            RegressionModel::Reciprocal => {
+                if x.contains(&0.0) {
+                    return Err(ERR_ZERO_X);
+                }
+                let inverse: Vec<f64> = x.iter().map(|t| 1.0 / t).collect();
+                let (slope, intercept) = least_squares(&inverse, y)?;
+                (intercept, slope)
+            }
+        };
+        let mut fit = ModelFit { model, a, b, r_squared: 0.0, rss: 0.0 };
+        let fitted: Vec<f64> = x.iter().map(|&t| fit.predict(t)).collect();
+        fit.r_squared = coefficient_of_determination(y, &fitted)?;
+        fit.rss = y.iter().zip(&fitted).map(|(a, b)| (a - b).powi(2)).sum();
+        Ok(fit)
+    }
+
+    // 依次拟合所有适用的模型, 按 R² 从大到小排列
+    pub fn compare_models(x: &[f64], y: &[f64]) -> Result<Vec<ModelFit>, &'static str> {
+        check_pairs(x, y)?;
+        let mut fits: Vec<ModelFit> = MODELS.iter().filter_map(|&m| fit_model(m, x, y).ok()).collect();
+        if fits.is_empty() {
+            return Err(ERR_NO_APPLICABLE_MODEL);
+        }
+        fits.sort_by(|p, q| q.r_squared.total_cmp(&p.r_squared));
+        Ok(fits)
+    }
+
+    // 多元线性回归 ŷ = b0 + b1·x1 + ... + bk·xk
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MultipleRegression {
+        // 第 0 项为截距
+        pub coefficients: Vec<f64>,
+        pub standard_errors: Vec<f64>,
+        pub t_statistics: Vec<f64>,
+        // 双侧检验 H0: b_j = 0 的 p 值
+        pub p_values: Vec<f64>,
+        pub r_squared: f64,
+        pub adjusted_r_squared: f64,
+        // 误差方差估计 σ̂² = RSS / (n - k - 1)
+        pub residual_variance: f64,
+        pub residuals: Vec<f64>,
+        pub rss: f64,
+        pub df: usize,
+        xtx_inverse: Vec<Vec<f64>>,
+    }
+
+    impl MultipleRegression {
+        pub fn predict(&self, x: &[f64]) -> Result<f64, &'static str> {
+            if x.len() + 1 != self.coefficients.len() {
+                return Err(ERR_PREDICTOR_COUNT);
+            }
+            Ok(self.coefficients[0] + x.iter().zip(&self.coefficients[1..]).map(|(a, b)| a * b).sum::<f64>())
+        }
+
+        // 新观测值的预测区间 ŷ ± t·σ̂·√(1 + x₀ᵀ(XᵀX)⁻¹x₀)
+        pub fn prediction_interval(&self, x: &[f64], confidence: f64) -> Result<(f64, f64), &'static str> {
+            self.interval(x, confidence, 1.0)
+        }
+
+        // 均值 E(y | x₀) 的置信区间, 根号内不含 1
+        pub fn confidence_interval(&self, x: &[f64], confidence: f64) -> Result<(f64, f64), &'static str> {
+            self.interval(x, confidence, 0.0)
+        }
+
+        fn interval(&self, x: &[f64], confidence: f64, extra: f64) -> Result<(f64, f64), &'static str> {
+            if confidence <= 0.0 || confidence >= 1.0 {
+                return Err(ERR_INVALID_CONFIDENCE);
+            }
+            let center = self.predict(x)?;
+            let row: Vec<f64> = std::iter::once(1.0).chain(x.iter().copied()).collect();
+            let leverage: f64 = row
+                .iter()
+                .zip(&self.xtx_inverse)
+                .map(|(a, inv)| a * inv.iter().zip(&row).map(|(m, b)| m * b).sum::<f64>())
+                .sum();
+            let t = student_t_quantile((1.0 + confidence) / 2.0, self.df)?;
+            let half = t * (self.residual_variance * (extra + leverage)).sqrt();
+            Ok((center - half, center + half))
+        }
+    }
+
+    // 每个自变量为一列, 设计矩阵的每行为 [1, x1, ..., xk]
+    pub fn design_matrix(predictors: &[Vec<f64>]) -> Result<Vec<Vec<f64>>, &'static str> {
+        if predictors.is_empty() {
+            return Err(ERR_NO_PREDICTORS);
+        }
+        let n = predictors[0].len();
+        if predictors.iter().any(|column| column.len() != n) {
+            return Err(ERR_MISMATCHED_DATA);
+        }
+        Ok((0..n).map(|i| std::iter::once(1.0).chain(predictors.iter().map(|column| column[i])).collect()).collect())
+    }
+
+    // XᵀX 对称正定, 用 Cholesky 分解 L·Lᵀ; 主元过小视为共线
+    fn cholesky(a: &[Vec<f64>]) -> Result<Vec<Vec<f64>>, &'static str> {
+        let m = a.len();
+        let mut l = vec![vec![0.0; m]; m];
+        for i in 0..m {
+            for j in 0..=i {
+                let sum: f64 = (0..j).map(|k| l[i][k] * l[j][k]).sum();
+                if i == j {
+                    let pivot = a[i][i] - sum;
+                    if pivot <= COLLINEAR_TOLERANCE * a[i][i] {
+                        return Err(ERR_COLLINEAR);
+                    }
+                    l[i][i] = pivot.sqrt();
+                } else {
+                    l[i][j] = (a[i][j] - sum) / l[j][j];
+                }
+            }
+        }
+        Ok(l)
+    }
+
+    // 解 L·Lᵀ·x = b
+    fn cholesky_solve(l: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+        let m = l.len();
+        let mut z = vec![0.0; m];
+        for i in 0..m {
+            z[i] = (b[i] - (0..i).map(|k| l[i][k] * z[k]).sum::<f64>()) / l[i][i];
+        }
+        let mut x = vec![0.0; m];
+        for i in (0..m).rev() {
+            x[i] = (z[i] - (i + 1..m).map(|k| l[k][i] * x[k]).sum::<f64>()) / l[i][i];
+        }
+        x
+    }
+
+    // 解正规方程 XᵀX·b = Xᵀy
+    pub fn multiple_regression(predictors: &[Vec<f64>], y: &[f64]) -> Result<MultipleRegression, &'static str> {
+        let x = design_matrix(predictors)?;
+        if x.len() != y.len() {
+            return Err(ERR_MISMATCHED_DATA);
+        }
+        if y.len() < 2 {
+            return Err(ERR_INSUFFICIENT_DATA);
+        }
+        let (n, m) = (y.len(), predictors.len() + 1);
+        if n < m {
+            return Err(ERR_TOO_FEW_OBSERVATIONS);
+        }
+        if n == m {
+            return Err(ERR_PERFECT_FIT);
+        }
+        let xtx: Vec<Vec<f64>> = (0..m).map(|i| (0..m).map(|j| x.iter().map(|row| row[i] * row[j]).sum()).collect()).collect();
+        let xty: Vec<f64> = (0..m).map(|i| x.iter().zip(y).map(|(row, b)| row[i] * b).sum()).collect();
+        let l = cholesky(&xtx)?;
+        let coefficients = cholesky_solve(&l, &xty);
+        let unit = |j: usize| (0..m).map(|i| if i == j { 1.0 } else { 0.0 }).collect::<Vec<f64>>();
+        let columns: Vec<Vec<f64>> = (0..m).map(|j| cholesky_solve(&l, &unit(j))).collect();
+        let xtx_inverse: Vec<Vec<f64>> = (0..m).map(|i| (0..m).map(|j| columns[j][i]).collect()).collect();
+
+        let residuals: Vec<f64> = x
+            .iter()
+            .zip(y)
+            .map(|(row, b)| b - row.iter().zip(&coefficients).map(|(p, q)| p * q).sum::<f64>())
+            .collect();
+        let rss: f64 = residuals.iter().map(|e| e * e).sum();
+        let mean = y.iter().sum::<f64>() / n as f64;
+        let tss: f64 = y.iter().map(|v| (v - mean).powi(2)).sum();
+        if tss < 1e-12 {
+            return Err(ERR_ZERO_Y_VARIANCE);
+        }
+        let df = n - m;
+        let residual_variance = rss / df as f64;
+        let r_squared = 1.0 - rss / tss;
+        let adjusted_r_squared = 1.0 - (1.0 - r_squared) * (n - 1) as f64 / df as f64;
+        let standard_errors: Vec<f64> = (0..m).map(|j| (residual_variance * xtx_inverse[j][j]).sqrt()).collect();
+        let t_statistics: Vec<f64> = coefficients.iter().zip(&standard_errors).map(|(b, se)| b / se).collect();
+        let p_values = t_statistics.iter().map(|t| 2.0 * (1.0 - student_t_cdf(t.abs(), df))).collect();
+
+        Ok(MultipleRegression {
+            coefficients,
+            standard_errors,
+            t_statistics,
+            p_values,
+            r_squared,
+            adjusted_r_squared,
+            residual_variance,
+            residuals,
+            rss,
+            df,
+            xtx_inverse,
+        })
+    }
 }
@@ -0,0 +1,362 @@
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+pub mod sample_spaces {
+    use crate::maths::algebra::fraction::fractions::Fraction;
+    use crate::maths::probability::probability::probability::is_independent;
+
+    const ERR_EMPTY_SPACE: &str = "样本空间至少需要一个试验步骤";
+    const ERR_EMPTY_STAGE: &str = "试验的可能结果不能为空";
+    const ERR_TOO_MANY_DRAWS: &str = "不放回抽取的个数不能超过总数";
+    const ERR_INVALID_WEIGHTS: &str = "各结果的概率必须非负且总和为1";
+    const ERR_TOO_LARGE: &str = "样本空间过大, 请改用 stagewise_probability 或 composition_probability";
+    const ERR_ZERO_PROBABILITY: &str = "条件事件的概率不能为0";
+    const ERR_STAGE_COUNT: &str = "各步骤的事件数量必须与试验步骤数量一致";
+    const ERR_INVALID_STAGE: &str = "试验步骤编号超出范围";
+    const ERR_NOT_UNIFORM: &str = "按公式计数只适用于等可能的抽取";
+    const ERR_OVERLAPPING_CATEGORIES: &str = "各类别必须互斥, 同一物件不能同时属于多个类别";
+
+    // 枚举的样本点数量上限
+    pub const MAX_OUTCOMES: u128 = 1_000_000;
+    pub const TAILS: u32 = 0;
+    pub const HEADS: u32 = 1;
+
+    // 单个试验步骤上的事件, 参数为该步骤抽到的结果
+    pub type StageEvent<'a> = &'a dyn Fn(&[u32]) -> bool;
+
+    // 物件类别的判定条件及要求抽到的个数
+    pub type Category<'a> = (&'a dyn Fn(u32) -> bool, usize);
+
+    // 扑克牌编号 0..52: 点数 1..=13, 花色 0..4
+    pub fn card_rank(card: u32) -> u32 {
+        card / 4 + 1
+    }
+
+    pub fn card_suit(card: u32) -> u32 {
+        card % 4
+    }
+
+    // 从 items 中抽取 draws 个, 每个物件等可能; 无序抽取的结果按升序排列
+    #[derive(Debug, Clone, PartialEq)]
+    enum Stage {
+        Draw { items: Vec<u32>, draws: usize, replacement: bool, ordered: bool },
+        Weighted(Vec<(u32, Fraction)>),
+    }
+
+    fn checked_power(base: u128, exp: usize) -> Option<u128> {
+        (0..exp).try_fold(1u128, |acc, _| acc.checked_mul(base))
+    }
+
+    fn falling(n: u128, k: usize) -> Option<u128> {
+        (0..k as u128).try_fold(1u128, |acc, i| acc.checked_mul(n - i))
+    }
+
+    fn choose(n: u128, k: usize) -> Option<u128> {
+        (0..k as u128).try_fold(1u128, |acc, i| acc.checked_mul(n - i).map(|x| x / (i + 1)))
+    }
+
+    impl Stage {
+        fn size(&self) -> Option<u128> {
+            match self {
+                Stage::Draw { items, draws, replacement, ordered } => {
+                    let n = items.len() as u128;
+                    match (replacement, ordered) {
+                        (true, _) => checked_power(n, *draws),
+                        (false, true) => falling(n, *draws),
+                        (false, false) => choose(n, *draws),
+                    }
+                }
+                Stage::Weighted(outcomes) => Some(outcomes.len() as u128),
+            }
+        }
+
+        // 有放回的无序抽取并非等可能, 仍按有序方式枚举, 只把结果排序
+        fn enumerate(&self) -> Vec<(Vec<u32>, Fraction)> {
+            match self {
+                Stage::Draw { items, draws, replacement, ordered } => {
+                    let n = items.len();
+                    let mut selections = Vec::new();
+                    let mut current = Vec::with_capacity(*draws);
+                    let mut used = vec![false; n];
+                    select((n, *draws, *replacement, *ordered), 0, &mut current, &mut used, &mut selections);
+                    let p = Fraction::new(1, selections.len() as i128).expect("样本点数量大于0");
+                    selections
+                        .into_iter()
+                        .map(|indices| {
+                            let mut values: Vec<u32> = indices.iter().map(|&i| items[i]).collect();
+                            if !ordered {
+                                values.sort_unstable();
+                            }
+                            (values, p)
+                        })
+                        .collect()
+                }
+                Stage::Weighted(outcomes) => outcomes.iter().map(|&(v, p)| (vec![v], p)).collect(),
+            }
+        }
+    }
+
+    // spec 为 (总数, 抽取个数, 是否放回, 是否有序)
+    fn select(spec: (usize, usize, bool, bool), start: usize, current: &mut Vec<usize>, used: &mut [bool], out: &mut Vec<Vec<usize>>) {
+        let (n, k, replacement, ordered) = spec;
+        if current.len() == k {
+            out.push(current.clone());
+            return;
+        }
+        let from = if !replacement && !ordered { start } else { 0 };
+        for i in from..n {
+            if !replacement && used[i] {
+                continue;
+            }
+            used[i] = true;
+            current.push(i);
+            select(spec, i + 1, current, used, out);
+            current.pop();
+            used[i] = false;
+        }
+    }
+
+    // 一个样本点: 每个试验步骤抽到的结果
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub struct Outcome {
+        pub stages: Vec<Vec<u32>>,
+    }
+
+    impl Outcome {
+        pub fn stage(&self, i: usize) -> &[u32] {
+            &self.stages[i]
+        }
+
+        pub fn values(&self) -> Vec<u32> {
+            self.stages.iter().flatten().copied().collect()
+        }
+
+        pub fn sum(&self) -> u32 {
+            self.stages.iter().flatten().sum()
+        }
+
+        pub fn count(&self, value: u32) -> usize {
+            self.stages.iter().flatten().filter(|&&v| v == value).count()
+        }
+    }
+
+    #[derive(Debug, Clone, Default)]
+    pub struct SampleSpaceBuilder {
+        stages: Vec<Stage>,
+        error: Option<&'static str>,
+    }
+
+    impl SampleSpaceBuilder {
+        pub fn new() -> SampleSpaceBuilder {
+            SampleSpaceBuilder::default()
+        }
+
+        fn push(mut self, stage: Result<Stage, &'static str>) -> SampleSpaceBuilder {
+            match stage {
+                Ok(stage) => self.stages.push(stage),
+                Err(e) => {
+                    self.error.get_or_insert(e);
+                }
+            }
+            self
+        }
+
+        fn draw(items: Vec<u32>, draws: usize, replacement: bool, ordered: bool) -> Result<Stage, &'static str> {
+            if items.is_empty() || draws == 0 {
+                return Err(ERR_EMPTY_STAGE);
+            }
+            if !replacement && draws > items.len() {
+                return Err(ERR_TOO_MANY_DRAWS);
+            }
+            Ok(Stage::Draw { items, draws, replacement, ordered })
+        }
+
+        // 掷一枚 sides 面的骰子, 点数 1..=sides
+        pub fn die(self, sides: u32) -> SampleSpaceBuilder {
+            let stage = SampleSpaceBuilder::draw((1..=sides).collect(), 1, true, true);
+            self.push(stage)
+        }
+
+        pub fn dice(self, count: usize, sides: u32) -> SampleSpaceBuilder {
+            (0..count).fold(self, |b, _| b.die(sides))
+        }
+
+        pub fn coin(self) -> SampleSpaceBuilder {
+            let stage = SampleSpaceBuilder::draw(vec![TAILS, HEADS], 1, true, true);
+            self.push(stage)
+        }
+
+        pub fn coins(self, count: usize) -> SampleSpaceBuilder {
+            (0..count).fold(self, |b, _| b.coin())
+        }
+
+        // 从一副52张的扑克牌中抽取
+        pub fn cards(self, draws: usize, replacement: bool, ordered: bool) -> SampleSpaceBuilder {
+            let stage = SampleSpaceBuilder::draw((0..52).collect(), draws, replacement, ordered);
+            self.push(stage)
+        }
+
+        // balls 为 (颜色编号, 个数), 同色的球视为可区分的不同个体
+        pub fn urn(self, balls: &[(u32, usize)], draws: usize, replacement: bool, ordered: bool) -> SampleSpaceBuilder {
+            let items = balls.iter().flat_map(|&(color, count)| std::iter::repeat_n(color, count)).collect();
+            let stage = SampleSpaceBuilder::draw(items, draws, replacement, ordered);
+            self.push(stage)
+        }
+
+        pub fn weighted(self, outcomes: &[(u32, Fraction)]) -> SampleSpaceBuilder {
+            let total = outcomes.iter().try_fold(Fraction::zero(), |acc, &(_, p)| acc.checked_add(p));
+            let stage = if outcomes.is_empty() {
+                Err(ERR_EMPTY_STAGE)
+            } else if total != Ok(Fraction::one()) || outcomes.iter().any(|&(_, p)| p < Fraction::zero()) {
+                Err(ERR_INVALID_WEIGHTS)
+            } else {
+                Ok(Stage::Weighted(outcomes.to_vec()))
+            };
+            self.push(stage)
+        }
+
+        pub fn build(self) -> Result<SampleSpace, &'static str> {
+            if let Some(e) = self.error {
+                return Err(e);
+            }
+            if self.stages.is_empty() {
+                return Err(ERR_EMPTY_SPACE);
+            }
+            let size = self.stages.iter().try_fold(1u128, |acc, s| s.size().and_then(|n| acc.checked_mul(n)));
+            let outcomes = match size {
+                Some(n) if n <= MAX_OUTCOMES => {
+                    let mut list = vec![(Vec::new(), Fraction::one())];
+                    for stage in &self.stages {
+                        let options = stage.enumerate();
+                        let mut next = Vec::with_capacity(list.len() * options.len());
+                        for (prefix, p) in &list {
+                            for (values, q) in &options {
+                                let mut stages: Vec<Vec<u32>> = prefix.clone();
+                                stages.push(values.clone());
+                                next.push((stages, p.checked_mul(*q)?));
+                            }
+                        }
+                        list = next;
+                    }
+                    Some(list.into_iter().map(|(stages, p)| (Outcome { stages }, p)).collect())
+                }
+                _ => None,
+            };
+            Ok(SampleSpace { stages: self.stages, size, outcomes })
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct SampleSpace {
+        stages: Vec<Stage>,
+        size: Option<u128>,
+        outcomes: Option<Vec<(Outcome, Fraction)>>,
+    }
+
+    impl SampleSpace {
+        // 样本点总数, 超出 u128 范围时为 None
+        pub fn size(&self) -> Option<u128> {
+            self.size
+        }
+
+        pub fn is_enumerated(&self) -> bool {
+            self.outcomes.is_some()
+        }
+
+        pub fn outcomes(&self) -> Result<&[(Outcome, Fraction)], &'static str> {
+            self.outcomes.as_deref().ok_or(ERR_TOO_LARGE)
+        }
+
+        pub fn probability<E>(&self, event: E) -> Result<Fraction, &'static str>
+        where
+            E: Fn(&Outcome) -> bool,
+        {
+            self.outcomes()?.iter().filter(|(o, _)| event(o)).try_fold(Fraction::zero(), |acc, (_, p)| acc.checked_add(*p))
+        }
+
+        // P(A|B) = P(AB) / P(B)
+        pub fn conditional_probability<A, B>(&self, a: A, b: B) -> Result<Fraction, &'static str>
+        where
+            A: Fn(&Outcome) -> bool,
+            B: Fn(&Outcome) -> bool,
+        {
+            let p_b = self.probability(&b)?;
+            if p_b.is_zero() {
+                return Err(ERR_ZERO_PROBABILITY);
+            }
+            self.probability(|o| a(o) && b(o))?.checked_div(p_b)
+        }
+
+        pub fn is_independent<A, B>(&self, a: A, b: B) -> Result<bool, &'static str>
+        where
+            A: Fn(&Outcome) -> bool,
+            B: Fn(&Outcome) -> bool,
+        {
+            let p_a = self.probability(&a)?;
+            let p_b = self.probability(&b)?;
+            let p_ab = self.probability(|o| a(o) && b(o))?;
+            is_independent(p_a.to_f64(), p_b.to_f64(), p_ab.to_f64())
+        }
+
+        // 各步骤相互独立, 事件为各步骤事件之交时 P = Π P_i, 只需分别枚举每一步
+        pub fn stagewise_probability(&self, events: &[StageEvent<'_>]) -> Result<Fraction, &'static str> {
+            if events.len() != self.stages.len() {
+                return Err(ERR_STAGE_COUNT);
+            }
+            self.stages.iter().zip(events).try_fold(Fraction::one(), |acc, (stage, event)| {
+                if stage.size().is_none_or(|n| n > MAX_OUTCOMES) {
+                    return Err(ERR_TOO_LARGE);
+                }
+                let p = stage.enumerate().iter().filter(|(v, _)| event(v)).try_fold(Fraction::zero(), |s, (_, q)| s.checked_add(*q))?;
+                acc.checked_mul(p)
+            })
+        }
+
+        // 第 stage 步抽到的物件中, 满足 categories[i].0 的恰有 categories[i].1 个(各类互斥),
+        // 按超几何或多项分布的计数公式计算, 无需枚举
+        pub fn composition_probability(&self, stage: usize, categories: &[Category<'_>]) -> Result<Fraction, &'static str> {
+            let (items, draws, replacement) = match self.stages.get(stage) {
+                Some(Stage::Draw { items, draws, replacement, .. }) => (items, *draws, *replacement),
+                Some(Stage::Weighted(_)) => return Err(ERR_NOT_UNIFORM),
+                None => return Err(ERR_INVALID_STAGE),
+            };
+            if items.iter().any(|&v| categories.iter().filter(|(f, _)| f(v)).count() > 1) {
+                return Err(ERR_OVERLAPPING_CATEGORIES);
+            }
+            let sizes: Vec<usize> = categories.iter().map(|(f, _)| items.iter().filter(|&&v| f(v)).count()).collect();
+            let wanted: usize = categories.iter().map(|c| c.1).sum();
+            let rest = items.len() - sizes.iter().sum::<usize>();
+            if wanted > draws {
+                return Ok(Fraction::zero());
+            }
+            let mut parts: Vec<(usize, usize)> = sizes.iter().zip(categories).map(|(&n, c)| (n, c.1)).collect();
+            parts.push((rest, draws - wanted));
+
+            let to_fraction = |x: Option<u128>| x.and_then(|v| i128::try_from(v).ok()).map(Fraction::from_integer).ok_or(ERR_TOO_LARGE);
+            if replacement {
+                // 多项分布: k! / Π c_i! · Π (n_i / N)^c_i
+                let total = Fraction::from(items.len() as i64);
+                let mut remaining = draws;
+                let mut p = Fraction::one();
+                for &(n, c) in &parts {
+                    let share = Fraction::from(n as i64).checked_div(total)?.powi(c as i32)?;
+                    p = p.checked_mul(to_fraction(choose(remaining as u128, c))?)?.checked_mul(share)?;
+                    remaining -= c;
+                }
+                Ok(p)
+            } else {
+                let mut favorable = Fraction::one();
+                for &(n, c) in &parts {
+                    if c > n {
+                        return Ok(Fraction::zero());
+                    }
+                    favorable = favorable.checked_mul(to_fraction(choose(n as u128, c))?)?;
+                }
+                favorable.checked_div(to_fraction(choose(items.len() as u128, draws))?)
+            }
+        }
+    }
+}
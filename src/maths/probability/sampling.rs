@@ -0,0 +1,159 @@
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+pub mod sampling {
+    use crate::maths::probability::simulation::simulation::Rng;
+
+    const ERR_EMPTY_POPULATION: &str = "总体不能为空";
+    const ERR_ZERO_SAMPLE: &str = "样本容量必须为正整数";
+    const ERR_SAMPLE_TOO_LARGE: &str = "不放回抽样的样本容量不能超过总体容量";
+    const ERR_EMPTY_STRATUM: &str = "每一层都不能为空";
+
+    // 从 0..population 中不放回地抽取 n 个编号, 部分 Fisher–Yates 洗牌, 保留抽中的先后顺序
+    fn draw_indices(rng: &mut Rng, population: usize, n: usize) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..population).collect();
+        for i in 0..n {
+            let j = i + rng.below((population - i) as u64).unwrap_or(0) as usize;
+            indices.swap(i, j);
+        }
+        indices.truncate(n);
+        indices
+    }
+
+    fn check_size(population: usize, n: usize, replacement: bool) -> Result<(), &'static str> {
+        if population == 0 {
+            return Err(ERR_EMPTY_POPULATION);
+        }
+        if n == 0 {
+            return Err(ERR_ZERO_SAMPLE);
+        }
+        if !replacement && n > population {
+            return Err(ERR_SAMPLE_TOO_LARGE);
+        }
+        Ok(())
+    }
+
+    // 简单随机抽样, 返回抽中个体在总体中的编号
+    pub fn simple_random_indices(population: usize, n: usize, replacement: bool, seed: u64) -> Result<Vec<usize>, &'static str> {
+        check_size(population, n, replacement)?;
+        let mut rng = Rng::new(seed);
+        if replacement {
+            return (0..n).map(|_| rng.below(population as u64).map(|i| i as usize)).collect();
+        }
+        Ok(draw_indices(&mut rng, population, n))
+    }
+
+    pub fn simple_random_sample<T: Clone>(population: &[T], n: usize, replacement: bool, seed: u64) -> Result<Vec<T>, &'static str> {
+        let indices = simple_random_indices(population.len(), n, replacement, seed)?;
+        Ok(indices.into_iter().map(|i| population[i].clone()).collect())
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SystematicSample<T> {
+        // 分段间隔 k
+        pub interval: usize,
+        // 第一段中随机确定的起始编号 (在剔除后的编号中)
+        pub start: usize,
+        // N 不能被 n 整除时先随机剔除的个体编号
+        pub removed: Vec<usize>,
+        pub indices: Vec<usize>,
+        pub items: Vec<T>,
+    }
+
+    // 系统抽样: 先随机剔除 N mod n 个个体, 再按 k = N' / n 分段, 第一段随机起点, 之后每隔 k 取一个
+    pub fn systematic_sample<T: Clone>(population: &[T], n: usize, seed: u64) -> Result<SystematicSample<T>, &'static str> {
+        check_size(population.len(), n, false)?;
+        let mut rng = Rng::new(seed);
+        let mut removed = draw_indices(&mut rng, population.len(), population.len() % n);
+        removed.sort_unstable();
+        let kept: Vec<usize> = (0..population.len()).filter(|i| removed.binary_search(i).is_err()).collect();
+        let interval = kept.len() / n;
+        let start = rng.below(interval as u64)? as usize;
+        let indices: Vec<usize> = (0..n).map(|j| kept[start + j * interval]).collect();
+        Ok(SystematicSample {
+            interval,
+            start,
+            removed,
+            items: indices.iter().map(|&i| population[i].clone()).collect(),
+            indices,
+        })
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Allocation {
+        pub population: usize,
+        // 按比例分配的精确份额 n·N_h / N
+        pub quota: f64,
+        pub size: usize,
+        // 取整时因小数部分较大而多分到 1 个
+        pub rounded_up: bool,
+    }
+
+    // 按比例分配, 先取整数部分, 剩余名额按小数部分从大到小 (相同时层较大者优先) 补足
+    pub fn proportional_allocation(sizes: &[usize], n: usize) -> Result<Vec<Allocation>, &'static str> {
+        if sizes.is_empty() {
+            return Err(ERR_EMPTY_POPULATION);
+        }
+        if sizes.contains(&0) {
+            return Err(ERR_EMPTY_STRATUM);
+        }
+        let total: usize = sizes.iter().sum();
+        check_size(total, n, false)?;
+        let mut allocations: Vec<Allocation> = sizes
+            .iter()
+            .map(|&population| {
+                let quota = n as f64 * population as f64 / total as f64;
+                Allocation {
+                    population,
+                    quota,
+                    size: (n * population) / total,
+                    rounded_up: false,
+                }
+            })
+            .collect();
+        let remaining = n - allocations.iter().map(|a| a.size).sum::<usize>();
+        let mut order: Vec<usize> = (0..sizes.len()).collect();
+        // 小数部分用整数余数比较, 避免浮点误差
+        let remainder = |h: usize| (n * sizes[h]) % total;
+        order.sort_by(|&i, &j| {
+            remainder(j)
+                .cmp(&remainder(i))
+                .then(sizes[j].cmp(&sizes[i]))
+                .then(i.cmp(&j))
+        });
+        for &h in order.iter().take(remaining) {
+            allocations[h].size += 1;
+            allocations[h].rounded_up = true;
+        }
+        Ok(allocations)
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Stratum<T> {
+        pub allocation: Allocation,
+        pub indices: Vec<usize>,
+        pub items: Vec<T>,
+    }
+
+    // 分层抽样: 各层按比例分配名额, 层内再做不放回简单随机抽样
+    pub fn stratified_sample<T: Clone>(strata: &[Vec<T>], n: usize, seed: u64) -> Result<Vec<Stratum<T>>, &'static str> {
+        let sizes: Vec<usize> = strata.iter().map(|s| s.len()).collect();
+        let allocations = proportional_allocation(&sizes, n)?;
+        let mut rng = Rng::new(seed);
+        Ok(strata
+            .iter()
+            .zip(allocations)
+            .map(|(members, allocation)| {
+                let indices = draw_indices(&mut rng, members.len(), allocation.size);
+                Stratum {
+                    allocation,
+                    items: indices.iter().map(|&i| members[i].clone()).collect(),
+                    indices,
+                }
+            })
+            .collect())
+    }
+}
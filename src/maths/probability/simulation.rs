@@ -0,0 +1,269 @@
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+pub mod simulation {
+    use crate::maths::probability::distribution::distributions::standard_normal_quantile;
+    use std::f64::consts::PI;
+
+    const ERR_INVALID_PROBABILITY: &str = "概率值必须在[0, 1]范围内";
+    const ERR_INVALID_RANGE: &str = "区间需满足a < b";
+    const ERR_INVALID_SIGMA: &str = "标准差σ必须为正数";
+    const ERR_EMPTY_DISTRIBUTION: &str = "分布数据不能为空";
+    const ERR_MISMATCHED: &str = "值与概率数量必须相等";
+    const ERR_PROBABILITY_SUM: &str = "概率总和必须为1";
+    const ERR_ZERO_BOUND: &str = "上界必须为正整数";
+    const ERR_NO_TRIALS: &str = "试验次数必须为正整数";
+    const ERR_INVALID_CONFIDENCE: &str = "置信水平必须在(0, 1)内";
+    const ERR_CONDITION_NEVER: &str = "模拟中条件事件从未发生";
+
+    const DEFAULT_CONFIDENCE: f64 = 0.95;
+
+    // SplitMix64, 只用于把一个种子扩展为 xoshiro 的 256 位状态
+    fn splitmix64(state: &mut u64) -> u64 {
+        *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = *state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // xoshiro256** 伪随机数发生器, 同一种子产生同一序列
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Rng {
+        state: [u64; 4],
+    }
+
+    impl Rng {
+        pub fn new(seed: u64) -> Rng {
+            let mut s = seed;
+            Rng {
+                state: [splitmix64(&mut s), splitmix64(&mut s), splitmix64(&mut s), splitmix64(&mut s)],
+            }
+        }
+
+        pub fn next_u64(&mut self) -> u64 {
+            let s = &mut self.state;
+            let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+            let t = s[1] << 17;
+            s[2] ^= s[0];
+            s[3] ^= s[1];
+            s[1] ^= s[2];
+            s[0] ^= s[3];
+            s[2] ^= t;
+            s[3] = s[3].rotate_left(45);
+            result
+        }
+
+        // [0, 1) 上的均匀分布, 取高 53 位
+        pub fn uniform(&mut self) -> f64 {
+            (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+        }
+
+        pub fn uniform_range(&mut self, a: f64, b: f64) -> Result<f64, &'static str> {
+            if a >= b {
+                return Err(ERR_INVALID_RANGE);
+            }
+            Ok(a + (b - a) * self.uniform())
+        }
+
+        // {0, 1, ..., n - 1} 上的等可能取值, 拒绝采样避免取模偏差
+        pub fn below(&mut self, n: u64) -> Result<u64, &'static str> {
+            if n == 0 {
+                return Err(ERR_ZERO_BOUND);
+            }
+            let zone = u64::MAX - u64::MAX % n;
+            loop {
+                let x = self.next_u64();
+                if x < zone {
+                    return Ok(x % n);
+                }
+            }
+        }
+
+        pub fn bernoulli(&mut self, p: f64) -> Result<bool, &'static str> {
+            if !(0.0..=1.0).contains(&p) {
+                return Err(ERR_INVALID_PROBABILITY);
+            }
+            Ok(self.uniform() < p)
+        }
+
+        // Box–Muller 变换
+        pub fn standard_normal(&mut self) -> f64 {
+            let u = 1.0 - self.uniform();
+            let v = self.uniform();
+            (-2.0 * u.ln()).sqrt() * (2.0 * PI * v).cos()
+        }
+
+        pub fn normal(&mut self, mu: f64, sigma: f64) -> Result<f64, &'static str> {
+            if sigma <= 0.0 {
+                return Err(ERR_INVALID_SIGMA);
+            }
+            Ok(mu + sigma * self.standard_normal())
+        }
+
+        // Fisher–Yates 洗牌
+        pub fn shuffle<T>(&mut self, items: &mut [T]) {
+            for i in (1..items.len()).rev() {
+                let j = self.below(i as u64 + 1).unwrap_or(0) as usize;
+                items.swap(i, j);
+            }
+        }
+    }
+
+    // 按分布列抽样, 预先计算累计概率, 每次抽样二分查找
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DiscreteTable {
+        values: Vec<f64>,
+        cumulative: Vec<f64>,
+    }
+
+    impl DiscreteTable {
+        pub fn new(values: &[f64], probabilities: &[f64]) -> Result<DiscreteTable, &'static str> {
+            if values.is_empty() {
+                return Err(ERR_EMPTY_DISTRIBUTION);
+            }
+            if values.len() != probabilities.len() {
+                return Err(ERR_MISMATCHED);
+            }
+            if probabilities.iter().any(|p| !(0.0..=1.0).contains(p)) {
+                return Err(ERR_INVALID_PROBABILITY);
+            }
+            let cumulative: Vec<f64> = probabilities
+                .iter()
+                .scan(0.0, |acc, p| {
+                    *acc += p;
+                    Some(*acc)
+                })
+                .collect();
+            if (cumulative[cumulative.len() - 1] - 1.0).abs() > 1e-9 {
+                return Err(ERR_PROBABILITY_SUM);
+            }
+            Ok(DiscreteTable {
+                values: values.to_vec(),
+                cumulative,
+            })
+        }
+
+        pub fn sample_index(&self, rng: &mut Rng) -> usize {
+            let u = rng.uniform() * self.cumulative[self.cumulative.len() - 1];
+            self.cumulative.partition_point(|&c| c <= u).min(self.values.len() - 1)
+        }
+
+        pub fn sample(&self, rng: &mut Rng) -> f64 {
+            self.values[self.sample_index(rng)]
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Estimate {
+        pub value: f64,
+        pub std_error: f64,
+        pub confidence: f64,
+        pub interval: (f64, f64),
+        pub trials: usize,
+    }
+
+    impl Estimate {
+        pub fn contains(&self, x: f64) -> bool {
+            self.interval.0 <= x && x <= self.interval.1
+        }
+    }
+
+    // 蒙特卡罗模拟: 独立重复 trials 次试验, 用频率估计概率、用样本均值估计期望
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct MonteCarlo {
+        trials: usize,
+        seed: u64,
+        confidence: f64,
+    }
+
+    impl MonteCarlo {
+        pub fn new(trials: usize, seed: u64) -> Result<MonteCarlo, &'static str> {
+            if trials == 0 {
+                return Err(ERR_NO_TRIALS);
+            }
+            Ok(MonteCarlo {
+                trials,
+                seed,
+                confidence: DEFAULT_CONFIDENCE,
+            })
+        }
+
+        pub fn with_confidence(self, confidence: f64) -> Result<MonteCarlo, &'static str> {
+            if confidence <= 0.0 || confidence >= 1.0 {
+                return Err(ERR_INVALID_CONFIDENCE);
+            }
+            Ok(MonteCarlo { confidence, ..self })
+        }
+
+        fn z(&self) -> Result<f64, &'static str> {
+            standard_normal_quantile((1.0 + self.confidence) / 2.0)
+        }
+
+        // 频率 hits / n 及其 Wilson 置信区间, 频率为 0 或 1 时仍有意义
+        fn proportion(&self, hits: usize, trials: usize) -> Result<Estimate, &'static str> {
+            let n = trials as f64;
+            let p = hits as f64 / n;
+            let z = self.z()?;
+            let center = (p + z * z / (2.0 * n)) / (1.0 + z * z / n);
+            let half = z / (1.0 + z * z / n) * (p * (1.0 - p) / n + z * z / (4.0 * n * n)).sqrt();
+            Ok(Estimate {
+                value: p,
+                std_error: (p * (1.0 - p) / n).sqrt(),
+                confidence: self.confidence,
+                interval: ((center - half).max(0.0), (center + half).min(1.0)),
+                trials,
+            })
+        }
+
+        pub fn probability<F: FnMut(&mut Rng) -> bool>(&self, mut event: F) -> Result<Estimate, &'static str> {
+            let mut rng = Rng::new(self.seed);
+            let hits = (0..self.trials).filter(|_| event(&mut rng)).count();
+            self.proportion(hits, self.trials)
+        }
+
+        // 随机变量的样本均值, 置信区间为 x̄ ± z·s/√n
+        pub fn expectation<F: FnMut(&mut Rng) -> f64>(&self, mut variable: F) -> Result<Estimate, &'static str> {
+            let mut rng = Rng::new(self.seed);
+            // Welford 算法累计均值与方差
+            let (mut mean, mut m2) = (0.0, 0.0);
+            for k in 1..=self.trials {
+                let x = variable(&mut rng);
+                let delta = x - mean;
+                mean += delta / k as f64;
+                m2 += delta * (x - mean);
+            }
+            let n = self.trials as f64;
+            let variance = if self.trials > 1 { m2 / (n - 1.0) } else { 0.0 };
+            let std_error = (variance / n).sqrt();
+            let half = self.z()? * std_error;
+            Ok(Estimate {
+                value: mean,
+                std_error,
+                confidence: self.confidence,
+                interval: (mean - half, mean + half),
+                trials: self.trials,
+            })
+        }
+
+        // 条件概率 P(B|A) 的频率估计, experiment 返回 (A 是否发生, B 是否发生), 只在 A 发生的试验中统计 B
+        pub fn conditional_probability<F: FnMut(&mut Rng) -> (bool, bool)>(&self, mut experiment: F) -> Result<Estimate, &'static str> {
+            let mut rng = Rng::new(self.seed);
+            let (mut given, mut hits) = (0, 0);
+            for _ in 0..self.trials {
+                let (a, b) = experiment(&mut rng);
+                if a {
+                    given += 1;
+                    hits += b as usize;
+                }
+            }
+            if given == 0 {
+                return Err(ERR_CONDITION_NEVER);
+            }
+            self.proportion(hits, given)
+        }
+    }
+}
@@ -12,6 +12,12 @@ pub mod probability {
     pub enum ProbabilityError {
         DntPositive,
         DntExist,
+        OutOfRange,
+        Mismatched,
+        TooFew,
+        ZeroWeight,
+        InvalidIntervals,
+        InvalidTotal,
     }
 
     impl fmt::Display for ProbabilityError {
@@ -19,34 +25,62 @@ pub mod probability {
             match self {
                 ProbabilityError::DntPositive => write!(f, "实参须为正数"),
                 ProbabilityError::DntExist => write!(f, "切片须为非空集合"),
+                ProbabilityError::OutOfRange => write!(f, "百分位数p须在[0, 100]范围内"),
+                ProbabilityError::Mismatched => write!(f, "数据与权重个数须相等"),
+                ProbabilityError::TooFew => write!(f, "样本方差至少需要2个数据"),
+                ProbabilityError::ZeroWeight => write!(f, "权重须非负且总和为正"),
+                ProbabilityError::InvalidIntervals => write!(f, "分组端点须严格递增, 且组数与频数个数一致"),
+                ProbabilityError::InvalidTotal => write!(f, "频率之和须为1"),
             }
         }
     }
 
     impl Error for ProbabilityError {}
 
-    fn checker(a: f64, b: f64) -> bool {
-        a >= 0.0 && b >= 0.0
+    // 箱线图中 1.5 倍四分位距的离群值界限
+    const FENCE_FACTOR: f64 = 1.5;
+    const TOTAL_TOLERANCE: f64 = 1e-9;
+    const HISTOGRAM_WIDTH: usize = 40;
+
+    fn sorted(data: &[f64]) -> Result<Vec<f64>, ProbabilityError> {
+        if data.is_empty() {
+            return Err(ProbabilityError::DntExist);
+        }
+        let mut sorted_data = data.to_vec();
+        sorted_data.sort_by(|a, b| a.total_cmp(b));
+        Ok(sorted_data)
     }
 
-    pub fn percentile(p: f64, data: &[f64]) -> Result<f64, ProbabilityError> {
-        if checker(p, 0.0) {
-            let mut sorted_data = data.to_vec();
-            sorted_data.sort_by(|a, b| a.partial_cmp(b).unwrap());
-            let p_normalized = p / 100.0;
-            let i = (sorted_data.len() as f64) * p_normalized;
-            if (i - i.floor()) < 1e-10 {
-                let idx = i as usize;
-                Ok((sorted_data[idx] + sorted_data[idx + 1]) / 2.0)
-            } else {
-                let idx = i as usize;
-                Ok(sorted_data[idx + 1])
-            }
+    // 教材规则: i = n × p%, i 为整数时取第 i 项与第 i + 1 项的平均数, 否则取第 ⌈i⌉ 项
+    fn sorted_percentile(p: f64, sorted_data: &[f64]) -> Result<f64, ProbabilityError> {
+        if !(0.0..=100.0).contains(&p) {
+            return Err(ProbabilityError::OutOfRange);
+        }
+        let n = sorted_data.len();
+        if p == 0.0 || n == 1 {
+            return Ok(sorted_data[0]);
+        }
+        if p == 100.0 {
+            return Ok(sorted_data[n - 1]);
+        }
+        // p 极接近 0 或 100 时 i 会舍入到 0 或 n, 下标需限制在有效范围内
+        let i = n as f64 * p / 100.0;
+        if (i - i.round()).abs() < 1e-10 {
+            let idx = (i.round() as usize).clamp(1, n - 1);
+            Ok((sorted_data[idx - 1] + sorted_data[idx]) / 2.0)
         } else {
-            Err(ProbabilityError::DntPositive)
+            Ok(sorted_data[(i.ceil() as usize).clamp(1, n) - 1])
         }
     }
 
+    pub fn percentile(p: f64, data: &[f64]) -> Result<f64, ProbabilityError> {
+        sorted_percentile(p, &sorted(data)?)
+    }
+
+    pub fn median(data: &[f64]) -> Result<f64, ProbabilityError> {
+        percentile(50.0, data)
+    }
+
     pub fn sample_mean(sample: &[f64]) -> Result<f64, ProbabilityError> {
         if sample.is_empty() {
             return Err(ProbabilityError::DntExist);
@@ -55,15 +89,290 @@ pub mod probability {
         Ok(sum / sample.len() as f64)
     }
 
+    fn squared_deviations(sample: &[f64]) -> Result<f64, ProbabilityError> {
+        let avg = sample_mean(sample)?;
+        Ok(sample.iter().map(|x| (x - avg).powi(2)).sum())
+    }
+
+    // s² = Σ(x - x̄)² / n
+    pub fn population_variance(sample: &[f64]) -> Result<f64, ProbabilityError> {
+        Ok(squared_deviations(sample)? / sample.len() as f64)
+    }
+
+    // s² = Σ(x - x̄)² / (n - 1)
     pub fn sample_variance(sample: &[f64]) -> Result<f64, ProbabilityError> {
-        if sample.is_empty() {
+        if sample.len() < 2 {
+            return Err(if sample.is_empty() { ProbabilityError::DntExist } else { ProbabilityError::TooFew });
+        }
+        Ok(squared_deviations(sample)? / (sample.len() - 1) as f64)
+    }
+
+    pub fn weighted_mean(values: &[f64], weights: &[f64]) -> Result<f64, ProbabilityError> {
+        if values.is_empty() {
             return Err(ProbabilityError::DntExist);
         }
-        let (square_sum, sum): (f64, f64) = sample.iter().fold((0.0, 0.0), |(s_sq, s), &num| {
-            (s_sq + num * num, s + num)
-        });
-        let avg = sum / sample.len() as f64;
-        let square_avg = square_sum / sample.len() as f64;
-        Ok(square_avg - avg * avg)
+        if values.len() != weights.len() {
+            return Err(ProbabilityError::Mismatched);
+        }
+        let total: f64 = weights.iter().sum();
+        if weights.iter().any(|&w| w < 0.0) || total <= 0.0 {
+            return Err(ProbabilityError::ZeroWeight);
+        }
+        Ok(values.iter().zip(weights).map(|(x, w)| x * w).sum::<f64>() / total)
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Summary {
+        pub n: usize,
+        pub mean: f64,
+        pub median: f64,
+        // 出现次数最多的数据; 各数据出现次数都相同时众数不存在, 为空
+        pub modes: Vec<f64>,
+        pub min: f64,
+        pub max: f64,
+        pub range: f64,
+        pub q1: f64,
+        pub q3: f64,
+        pub iqr: f64,
+        pub population_variance: f64,
+        pub population_std_dev: f64,
+        // 只有1个数据时样本方差无定义
+        pub sample_variance: Option<f64>,
+        pub sample_std_dev: Option<f64>,
+        // 三阶中心矩与标准差立方之比, 数据全相同时为 0
+        pub skewness: f64,
+    }
+
+    impl Summary {
+        pub fn new(data: &[f64]) -> Result<Summary, ProbabilityError> {
+            let sorted_data = sorted(data)?;
+            let n = sorted_data.len();
+            let mean = sorted_data.iter().sum::<f64>() / n as f64;
+            let (m2, m3) = sorted_data.iter().fold((0.0, 0.0), |(m2, m3), &x| {
+                let d = x - mean;
+                (m2 + d * d, m3 + d * d * d)
+            });
+            let population_variance = m2 / n as f64;
+            let sample_variance = (n > 1).then(|| m2 / (n - 1) as f64);
+            let skewness = if population_variance > 1e-12 {
+                m3 / n as f64 / population_variance.powf(1.5)
+            } else {
+                0.0
+            };
+
+            // 排序后相同数据相邻, 按游程统计频数
+            let mut runs: Vec<(f64, usize)> = Vec::new();
+            for &x in &sorted_data {
+                match runs.last_mut() {
+                    Some((value, count)) if *value == x => *count += 1,
+                    _ => runs.push((x, 1)),
+                }
+            }
+            let top = runs.iter().map(|r| r.1).max().unwrap_or(0);
+            let modes = if runs.len() > 1 && runs.iter().all(|r| r.1 == top) {
+                Vec::new()
+            } else {
+                runs.iter().filter(|r| r.1 == top).map(|r| r.0).collect()
+            };
+
+            let q1 = sorted_percentile(25.0, &sorted_data)?;
+            let q3 = sorted_percentile(75.0, &sorted_data)?;
+            let (min, max) = (sorted_data[0], sorted_data[n - 1]);
+            Ok(Summary {
+                n,
+                mean,
+                median: sorted_percentile(50.0, &sorted_data)?,
+                modes,
+                min,
+                max,
+                range: max - min,
+                q1,
+                q3,
+                iqr: q3 - q1,
+                population_variance,
+                population_std_dev: population_variance.sqrt(),
+                sample_variance,
+                sample_std_dev: sample_variance.map(f64::sqrt),
+                skewness,
+            })
+        }
+
+        // 箱线图的离群值界限 [Q1 - 1.5·IQR, Q3 + 1.5·IQR]
+        pub fn fences(&self) -> (f64, f64) {
+            (self.q1 - FENCE_FACTOR * self.iqr, self.q3 + FENCE_FACTOR * self.iqr)
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct BoxPlot {
+        pub q1: f64,
+        pub median: f64,
+        pub q3: f64,
+        pub lower_fence: f64,
+        pub upper_fence: f64,
+        // 须线延伸到界限内的最小值与最大值
+        pub lower_whisker: f64,
+        pub upper_whisker: f64,
+        pub outliers: Vec<f64>,
+    }
+
+    pub fn box_plot(data: &[f64]) -> Result<BoxPlot, ProbabilityError> {
+        let summary = Summary::new(data)?;
+        let (lower_fence, upper_fence) = summary.fences();
+        let mut inside = sorted(data)?;
+        let outliers: Vec<f64> = inside.iter().copied().filter(|&x| x < lower_fence || x > upper_fence).collect();
+        inside.retain(|&x| x >= lower_fence && x <= upper_fence);
+        Ok(BoxPlot {
+            q1: summary.q1,
+            median: summary.median,
+            q3: summary.q3,
+            lower_fence,
+            upper_fence,
+            lower_whisker: inside[0],
+            upper_whisker: inside[inside.len() - 1],
+            outliers,
+        })
+    }
+
+    // 频率分布直方图: 相邻分组 [e_i, e_(i+1)), 频率之和为 1
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GroupedData {
+        edges: Vec<f64>,
+        frequencies: Vec<f64>,
+        // 由频数构造时记录样本容量
+        total: Option<f64>,
+    }
+
+    impl GroupedData {
+        fn checked(edges: &[f64], values: &[f64]) -> Result<(), ProbabilityError> {
+            if values.is_empty() || edges.len() != values.len() + 1 || edges.windows(2).any(|w| w[0] >= w[1]) {
+                return Err(ProbabilityError::InvalidIntervals);
+            }
+            if values.iter().any(|&v| v < 0.0) {
+                return Err(ProbabilityError::DntPositive);
+            }
+            Ok(())
+        }
+
+        pub fn from_counts(edges: &[f64], counts: &[f64]) -> Result<GroupedData, ProbabilityError> {
+            GroupedData::checked(edges, counts)?;
+            let total: f64 = counts.iter().sum();
+            if total <= 0.0 {
+                return Err(ProbabilityError::InvalidTotal);
+            }
+            Ok(GroupedData {
+                edges: edges.to_vec(),
+                frequencies: counts.iter().map(|c| c / total).collect(),
+                total: Some(total),
+            })
+        }
+
+        pub fn from_frequencies(edges: &[f64], frequencies: &[f64]) -> Result<GroupedData, ProbabilityError> {
+            GroupedData::checked(edges, frequencies)?;
+            if (frequencies.iter().sum::<f64>() - 1.0).abs() > TOTAL_TOLERANCE {
+                return Err(ProbabilityError::InvalidTotal);
+            }
+            Ok(GroupedData {
+                edges: edges.to_vec(),
+                frequencies: frequencies.to_vec(),
+                total: None,
+            })
+        }
+
+        // 直方图的纵轴为 频率/组距, 各小矩形面积之和为 1
+        pub fn from_heights(edges: &[f64], heights: &[f64]) -> Result<GroupedData, ProbabilityError> {
+            GroupedData::checked(edges, heights)?;
+            let frequencies: Vec<f64> = heights.iter().zip(edges.windows(2)).map(|(h, w)| h * (w[1] - w[0])).collect();
+            GroupedData::from_frequencies(edges, &frequencies)
+        }
+
+        pub fn edges(&self) -> &[f64] {
+            &self.edges
+        }
+
+        pub fn frequencies(&self) -> &[f64] {
+            &self.frequencies
+        }
+
+        pub fn total(&self) -> Option<f64> {
+            self.total
+        }
+
+        pub fn counts(&self) -> Option<Vec<f64>> {
+            self.total.map(|n| self.frequencies.iter().map(|f| f * n).collect())
+        }
+
+        pub fn heights(&self) -> Vec<f64> {
+            self.frequencies.iter().zip(self.edges.windows(2)).map(|(f, w)| f / (w[1] - w[0])).collect()
+        }
+
+        pub fn midpoints(&self) -> Vec<f64> {
+            self.edges.windows(2).map(|w| (w[0] + w[1]) / 2.0).collect()
+        }
+
+        // 平均数估计值: 各组中点乘以频率之和
+        pub fn mean(&self) -> f64 {
+            self.midpoints().iter().zip(&self.frequencies).map(|(m, f)| m * f).sum()
+        }
+
+        pub fn variance(&self) -> f64 {
+            let mean = self.mean();
+            self.midpoints().iter().zip(&self.frequencies).map(|(m, f)| (m - mean).powi(2) * f).sum()
+        }
+
+        pub fn std_dev(&self) -> f64 {
+            self.variance().sqrt()
+        }
+
+        // 众数估计值: 最高矩形底边中点, 并列时全部给出
+        pub fn modes(&self) -> Vec<f64> {
+            let heights = self.heights();
+            let top = heights.iter().cloned().fold(f64::MIN, f64::max);
+            self.midpoints()
+                .into_iter()
+                .zip(&heights)
+                .filter(|(_, h)| (*h - top).abs() < TOTAL_TOLERANCE)
+                .map(|(m, _)| m)
+                .collect()
+        }
+
+        // 找到累计频率达到 p% 的分组, 在组内按面积线性插值
+        pub fn percentile(&self, p: f64) -> Result<f64, ProbabilityError> {
+            if !(0.0..=100.0).contains(&p) {
+                return Err(ProbabilityError::OutOfRange);
+            }
+            let target = p / 100.0;
+            let mut cumulative = 0.0;
+            for (i, &f) in self.frequencies.iter().enumerate() {
+                if f > 0.0 && cumulative + f >= target - TOTAL_TOLERANCE {
+                    let (lo, hi) = (self.edges[i], self.edges[i + 1]);
+                    return Ok(lo + ((target - cumulative) / f).clamp(0.0, 1.0) * (hi - lo));
+                }
+                cumulative += f;
+            }
+            Ok(self.edges[self.edges.len() - 1])
+        }
+
+        pub fn median(&self) -> f64 {
+            self.percentile(50.0).unwrap_or(f64::NAN)
+        }
+    }
+
+    // 每行一组: 区间、按最高矩形缩放的条形、频率/组距
+    impl fmt::Display for GroupedData {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let heights = self.heights();
+            let top = heights.iter().cloned().fold(0.0, f64::max);
+            let labels: Vec<String> = self.edges.windows(2).map(|w| format!("[{}, {})", w[0], w[1])).collect();
+            let width = labels.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+            for (i, (label, h)) in labels.iter().zip(&heights).enumerate() {
+                let bar = if top > 0.0 { (h / top * HISTOGRAM_WIDTH as f64).round() as usize } else { 0 };
+                if i > 0 {
+                    writeln!(f)?;
+                }
+                write!(f, "{:<width$} | {} {:.4}", label, "█".repeat(bar), h, width = width)?;
+            }
+            Ok(())
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,139 @@
+/**
+ * Author:  Raye Lattice  
+ * Repo:    guhs
+ * Created: 10/18/2026
+ */
+
+pub mod discrete_variables {
+    use crate::maths::algebra::fraction::fractions::Fraction;
+    use crate::maths::probability::sample_space::sample_spaces::{Outcome, SampleSpace};
+    use std::collections::BTreeMap;
+    use std::fmt;
+
+    const ERR_EMPTY_TABLE: &str = "分布列不能为空";
+    const ERR_NEGATIVE_PROBABILITY: &str = "概率不能为负数";
+    const ERR_PROBABILITY_SUM: &str = "概率总和必须为1";
+
+    // 精确的分布列: 取值按升序排列, 相同取值已合并, 概率均为正
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct DistributionTable {
+        entries: Vec<(Fraction, Fraction)>,
+    }
+
+    impl DistributionTable {
+        pub fn new(entries: &[(Fraction, Fraction)]) -> Result<DistributionTable, &'static str> {
+            if entries.is_empty() {
+                return Err(ERR_EMPTY_TABLE);
+            }
+            if entries.iter().any(|&(_, p)| p < Fraction::zero()) {
+                return Err(ERR_NEGATIVE_PROBABILITY);
+            }
+            if entries.iter().try_fold(Fraction::zero(), |acc, &(_, p)| acc.checked_add(p))? != Fraction::one() {
+                return Err(ERR_PROBABILITY_SUM);
+            }
+            DistributionTable::merged(entries.iter().map(|&e| Ok(e)))
+        }
+
+        // 各项在合并前可能已经溢出, 因此逐项接受 Result
+        fn merged<I>(entries: I) -> Result<DistributionTable, &'static str>
+        where
+            I: Iterator<Item = Result<(Fraction, Fraction), &'static str>>,
+        {
+            let mut table: BTreeMap<Fraction, Fraction> = BTreeMap::new();
+            for entry in entries {
+                let (x, p) = entry?;
+                let slot = table.entry(x).or_insert_with(Fraction::zero);
+                *slot = slot.checked_add(p)?;
+            }
+            Ok(DistributionTable {
+                entries: table.into_iter().filter(|(_, p)| !p.is_zero()).collect(),
+            })
+        }
+
+        // 以样本点的函数定义随机变量, 如 "取出红球的个数"
+        pub fn from_sample_space<X, V>(space: &SampleSpace, variable: X) -> Result<DistributionTable, &'static str>
+        where
+            X: Fn(&Outcome) -> V,
+            V: Into<Fraction>,
+        {
+            let outcomes = space.outcomes()?;
+            DistributionTable::merged(outcomes.iter().map(|(o, p)| Ok((variable(o).into(), *p))))
+        }
+
+        pub fn entries(&self) -> &[(Fraction, Fraction)] {
+            &self.entries
+        }
+
+        pub fn values(&self) -> Vec<Fraction> {
+            self.entries.iter().map(|e| e.0).collect()
+        }
+
+        pub fn probabilities(&self) -> Vec<Fraction> {
+            self.entries.iter().map(|e| e.1).collect()
+        }
+
+        pub fn probability(&self, x: Fraction) -> Fraction {
+            self.entries.iter().find(|e| e.0 == x).map_or(Fraction::zero(), |e| e.1)
+        }
+
+        pub fn probability_where<F: Fn(Fraction) -> bool>(&self, event: F) -> Result<Fraction, &'static str> {
+            self.entries.iter().filter(|e| event(e.0)).try_fold(Fraction::zero(), |acc, e| acc.checked_add(e.1))
+        }
+
+        // E(X) = Σ x_i·p_i
+        pub fn mean(&self) -> Result<Fraction, &'static str> {
+            self.entries.iter().try_fold(Fraction::zero(), |acc, &(x, p)| acc.checked_add(x.checked_mul(p)?))
+        }
+
+        // D(X) = Σ (x_i - E(X))²·p_i
+        pub fn variance(&self) -> Result<Fraction, &'static str> {
+            let mean = self.mean()?;
+            self.entries.iter().try_fold(Fraction::zero(), |acc, &(x, p)| {
+                let d = x.checked_sub(mean)?;
+                acc.checked_add(d.checked_mul(d)?.checked_mul(p)?)
+            })
+        }
+
+        pub fn std_dev(&self) -> Result<f64, &'static str> {
+            Ok(self.variance()?.to_f64().sqrt())
+        }
+
+        // 转为 expected_value、variance 所接受的 (取值, 概率) 形式
+        pub fn to_table(&self) -> (Vec<f64>, Vec<f64>) {
+            self.entries.iter().map(|&(x, p)| (x.to_f64(), p.to_f64())).unzip()
+        }
+
+        // Y = aX + b, 有 E(Y) = aE(X) + b, D(Y) = a²D(X)
+        pub fn linear(&self, a: Fraction, b: Fraction) -> Result<DistributionTable, &'static str> {
+            DistributionTable::merged(self.entries.iter().map(|&(x, p)| Ok((a.checked_mul(x)?.checked_add(b)?, p))))
+        }
+
+        // X 与 Y 相互独立时, X + Y 的分布列为二者的卷积
+        pub fn add_independent(&self, other: &DistributionTable) -> Result<DistributionTable, &'static str> {
+            DistributionTable::merged(
+                self.entries
+                    .iter()
+                    .flat_map(|&(x, p)| other.entries.iter().map(move |&(y, q)| Ok((x.checked_add(y)?, p.checked_mul(q)?)))),
+            )
+        }
+    }
+
+    // 分布列的标准表格形式
+    impl fmt::Display for DistributionTable {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let values: Vec<String> = self.entries.iter().map(|e| e.0.to_string()).collect();
+            let probabilities: Vec<String> = self.entries.iter().map(|e| e.1.to_string()).collect();
+            let widths: Vec<usize> = values.iter().zip(&probabilities).map(|(x, p)| x.len().max(p.len())).collect();
+            let row = |label: &str, cells: &[String]| {
+                cells
+                    .iter()
+                    .zip(&widths)
+                    .fold(label.to_string(), |acc, (cell, &w)| format!("{} | {:^w$}", acc, cell, w = w))
+            };
+            let rule: String = widths.iter().fold("-".to_string(), |acc, &w| format!("{}-+-{}", acc, "-".repeat(w)));
+            writeln!(f, "{}", row("X", &values).trim_end())?;
+            writeln!(f, "{}", rule)?;
+            write!(f, "{}", row("P", &probabilities).trim_end())
+        }
+    }
+}
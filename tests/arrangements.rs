@@ -0,0 +1,50 @@
+use guhs::maths::discrete::arrangement::arrangement::*;
+
+fn check(a: &Arrangement, expected: u64, method: Method) {
+    let s = a.solve().unwrap();
+    assert_eq!(s.count, expected, "{:?}", a);
+    assert_eq!(s.method, method, "{:?}", a);
+    assert_eq!(s.verified(), Some(true), "{:?} {:?}", a, s);
+}
+
+#[test]
+fn arrangements() {
+    use Technique::*;
+    check(&Arrangement::new(5), 120, Method::Formula(vec![]));
+    check(&Arrangement::new(5).adjacent(&[0, 1]), 48, Method::Formula(vec![Bundling]));
+    // 3 男 3 女, 女生互不相邻
+    check(&Arrangement::new(6).not_adjacent(&[3, 4, 5]), 144, Method::Formula(vec![GapInsertion]));
+    check(&Arrangement::new(6).adjacent(&[0, 1]).not_adjacent(&[3, 4, 5]), 24, Method::Formula(vec![Bundling, GapInsertion]));
+    check(&Arrangement::new(5).not_at_ends(2), 72, Method::Formula(vec![InclusionExclusion]));
+    check(&Arrangement::new(5).not_at(0, &[0]).not_at(1, &[4]), 78, Method::Formula(vec![InclusionExclusion]));
+    check(&Arrangement::new(5).at(0, 2), 24, Method::Formula(vec![InclusionExclusion]));
+    // 错位排列
+    let d = (0..5).fold(Arrangement::new(5), |a, i| a.not_at(i, &[i]));
+    check(&d, 44, Method::Formula(vec![InclusionExclusion]));
+    check(&Arrangement::new(5).adjacent(&[0, 1]).not_at_ends(2), 24, Method::Formula(vec![Bundling, InclusionExclusion]));
+    check(&Arrangement::new(6).ordered(&[0, 1, 2]), 120, Method::Formula(vec![FixedOrder]));
+    check(&Arrangement::new(5).adjacent(&[0, 1]).not_at(0, &[2]), 36, Method::BruteForce);
+    check(&Arrangement::new(5).adjacent(&[0, 1]).adjacent(&[1, 2]), 12, Method::BruteForce);
+    assert!(Arrangement::new(5).adjacent(&[0]).solve().is_err());
+    assert!(Arrangement::new(5).not_at(7, &[0]).solve().is_err());
+    let big = Arrangement::new(15).adjacent(&[0, 1]).solve().unwrap();
+    assert_eq!(big.brute_force, None);
+}
+
+#[test]
+fn groups() {
+    let s = GroupDistribution::new(5, 3).solve().unwrap();
+    assert_eq!(s.count, 150);
+    assert_eq!(s.verified(), Some(true));
+    let s = GroupDistribution::new(5, 3).unlabeled().solve().unwrap();
+    assert_eq!((s.count, s.verified()), (25, Some(true)));
+    let s = GroupDistribution::new(6, 3).sizes(&[2, 2, 2]).unlabeled().solve().unwrap();
+    assert_eq!((s.count, s.verified()), (15, Some(true)));
+    let s = GroupDistribution::new(6, 3).sizes(&[1, 2, 3]).solve().unwrap();
+    assert_eq!((s.count, s.verified()), (360, Some(true)));
+    assert!(GroupDistribution::new(3, 4).solve().is_err());
+    assert!(GroupDistribution::new(5, 2).sizes(&[2, 2]).solve().is_err());
+    // 20 人分给 10 个对象且每组非空: 10!·S(20, 10) 约为 2.1e19, 超出 u64
+    assert!(GroupDistribution::new(20, 10).solve().is_err());
+    assert!(GroupDistribution::new(20, 10).unlabeled().solve().is_ok());
+}
@@ -0,0 +1,55 @@
+use guhs::maths::analytic::conic::conic_sections::*;
+
+fn general(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> GeneralConic {
+    GeneralConic { a, b, c, d, e, f }
+}
+
+// 标准方程上的点经坐标变换后应落在原曲线上
+fn check(g: GeneralConic, kind: ConicKind) -> ClassifiedConic {
+    let c = classify_conic(&g).unwrap();
+    assert_eq!(c.kind, kind, "{:?}", c);
+    let canonical_points: Vec<(f64, f64)> = match c.form {
+        CanonicalForm::Ellipse(e) => (0..8).map(|i| (e.a * (i as f64).cos(), e.b * (i as f64).sin())).collect(),
+        CanonicalForm::Hyperbola(h) => (0..8)
+            .map(|i| i as f64 * 0.3 - 1.0)
+            .map(|t| (h.a * t.cosh(), h.b * t.sinh()))
+            .collect(),
+        CanonicalForm::Parabola(p) => (0..8)
+            .map(|i| i as f64 * 0.5 - 2.0)
+            .map(|t| (t * t / (2.0 * p.p), t))
+            .collect(),
+        CanonicalForm::Circle(r) => (0..8).map(|i| (r * (i as f64).cos(), r * (i as f64).sin())).collect(),
+        CanonicalForm::Degenerate => Vec::new(),
+    };
+    for (x, y) in canonical_points {
+        let (gx, gy) = c.transform.from_canonical(x, y);
+        assert!(g.value(gx, gy).abs() < 1e-8, "{}", g.value(gx, gy));
+        let (bx, by) = c.transform.to_canonical(gx, gy);
+        assert!((bx - x).abs() < 1e-9 && (by - y).abs() < 1e-9);
+    }
+    c
+}
+
+#[test]
+fn classify_non_degenerate() {
+    // x² + xy + y² = 3, 旋转 45° 的椭圆
+    check(general(1.0, 1.0, 1.0, 0.0, 0.0, -3.0), ConicKind::Ellipse);
+    // 4(x-1)² + (y+2)² = 16, 长轴平行于 y 轴
+    check(general(4.0, 0.0, 1.0, -8.0, 4.0, -8.0), ConicKind::Ellipse);
+    check(general(1.0, 0.0, 1.0, -2.0, 0.0, -3.0), ConicKind::Circle);
+    check(general(0.0, 2.0, 0.0, 0.0, 0.0, -1.0), ConicKind::Hyperbola);
+    check(general(-1.0, 0.0, 4.0, 0.0, 0.0, -4.0), ConicKind::Hyperbola);
+    check(general(1.0, 0.0, 0.0, 0.0, -4.0, 0.0), ConicKind::Parabola);
+    check(general(1.0, -2.0, 1.0, -1.0, -3.0, 2.0), ConicKind::Parabola);
+    check(general(0.0, 0.0, 1.0, 3.0, 0.0, 0.0), ConicKind::Parabola);
+}
+
+#[test]
+fn classify_degenerate() {
+    check(general(1.0, 0.0, 1.0, 0.0, 0.0, 0.0), ConicKind::Point);
+    check(general(1.0, 0.0, 1.0, 0.0, 0.0, 1.0), ConicKind::Empty);
+    check(general(1.0, 0.0, -1.0, 0.0, 0.0, 0.0), ConicKind::IntersectingLines);
+    check(general(1.0, 0.0, 0.0, 0.0, 0.0, -1.0), ConicKind::ParallelLines);
+    check(general(1.0, 2.0, 1.0, 0.0, 0.0, 0.0), ConicKind::CoincidentLines);
+    assert!(classify_conic(&general(0.0, 0.0, 0.0, 1.0, 0.0, 0.0)).is_err());
+}
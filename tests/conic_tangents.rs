@@ -0,0 +1,54 @@
+use guhs::maths::analytic::conic::conic_sections::*;
+
+#[test]
+fn tangents_and_reflection() {
+    let e = Ellipse::new(2.0, 1.0).unwrap();
+    let t = tangent_at(&e, 2.0, 0.0).unwrap();
+    assert!(t.is_vertical() && t.contains(2.0, 3.0));
+    assert!(tangent_at(&e, 1.0, 1.0).is_err());
+    let n = normal_at(&e, 0.0, 1.0).unwrap();
+    assert!(n.is_vertical());
+    // 过 (0,2) 作 x²/4 + y² = 1 的两条切线
+    let tp = tangents_from_point(&e, 0.0, 2.0).unwrap();
+    assert_eq!(tp.lines.len(), 2);
+    for (l, p) in tp.lines.iter().zip(&tp.points) {
+        assert!(l.contains(0.0, 2.0) && l.contains(p.0, p.1));
+        let c = line_conic_intersection(&e, l).unwrap();
+        assert_eq!(c.position, LinePosition::Tangent, "{:?}", c);
+    }
+    assert!((tp.polar.y_intercept().unwrap() - 0.5).abs() < 1e-12);
+    assert!(tangents_from_point(&e, 0.0, 0.0).is_err());
+    // 斜率 k = 1 的切线: m² = a²k² + b² = 5
+    let ls = tangents_with_slope(&e, 1.0).unwrap();
+    assert_eq!(ls.len(), 2);
+    for l in &ls {
+        assert!((l.y_intercept().unwrap().abs() - 5f64.sqrt()).abs() < 1e-9);
+    }
+    let p = Parabola::new(2.0).unwrap();
+    let ls = tangents_with_slope(&p, 1.0).unwrap();
+    assert_eq!(ls.len(), 1);
+    assert!((ls[0].y_intercept().unwrap() - 1.0).abs() < 1e-12);
+    assert!(tangents_with_slope(&p, 0.0).unwrap().is_empty());
+    let h = Hyperbola::new(1.0, 1.0).unwrap();
+    assert!(tangents_with_slope(&h, 1.0).unwrap().is_empty());
+    assert_eq!(tangents_with_slope(&h, 2.0).unwrap().len(), 2);
+    // 点圆 (x-cx)² + (y-cy)² = 0: 判别式只因舍入偏离0, 每个斜率恰有一条经过该点的直线
+    for (cx, cy, k) in [(0.1, 0.3, 0.7), (0.3, -0.7, 1.3), (1.1, 2.9, -0.45)] {
+        let g = GeneralConic { a: 1.0, b: 0.0, c: 1.0, d: -2.0 * cx, e: -2.0 * cy, f: cx * cx + cy * cy };
+        let ls = tangents_with_slope(&g, k).unwrap();
+        assert_eq!(ls.len(), 1, "{:?}", ls);
+        assert!(ls[0].distance_to(cx, cy).unwrap() < 1e-6);
+    }
+    let pts = tangents_from_point(&p, -1.0, 0.0).unwrap();
+    assert_eq!(pts.lines.len(), 2);
+    for th in [0.3, 1.0, 2.5, 4.0] {
+        let (x, y) = (2.0 * f64::cos(th), f64::sin(th));
+        assert!(verify_reflection_ellipse(&e, x, y).unwrap());
+        let (x, y) = (1.0 / f64::cos(th), f64::tan(th));
+        assert!(verify_reflection_hyperbola(&h, x, y).unwrap());
+        let (x, y) = (th * th / 4.0, th);
+        assert!(verify_reflection_parabola(&p, x, y).unwrap());
+        assert!(verify_reflection_parabola(&p, x, -y).unwrap());
+    }
+    assert!(verify_reflection_parabola(&p, 0.0, 0.0).unwrap());
+}
@@ -0,0 +1,40 @@
+use guhs::maths::probability::contingency::contingency_tables::*;
+use guhs::maths::probability::distribution::distributions::*;
+
+#[test]
+fn chi_cdf() {
+    assert!((chi_squared_cdf(3.841, 1) - 0.95).abs() < 1e-4);
+    assert!((chi_squared_cdf(2.0, 2) - (1.0 - (-1.0f64).exp())).abs() < 1e-14);
+    assert!((chi_squared_cdf(29.588, 10) - 0.999).abs() < 1e-5);
+    assert!((1.0 - chi_squared_cdf(100.0, 3)).abs() < 1e-15);
+    for df in 1..=10 {
+        for (j, &a) in ALPHA_LEVELS.iter().enumerate() {
+            let c = critical_value(df, a).unwrap();
+            assert!(((1.0 - chi_squared_cdf(c, df)) - a).abs() < a * 0.01, "{} {} {}", df, a, j);
+        }
+    }
+    let c = critical_value(12, 0.05).unwrap();
+    assert!((c - 21.026).abs() < 1e-3, "{}", c);
+}
+
+#[test]
+fn tables() {
+    let t = ContingencyTable::two_by_two(40.0, 10.0, 20.0, 30.0).unwrap();
+    let k = t.statistic().unwrap();
+    assert!((k - 100.0 * (1200.0f64 - 200.0).powi(2) / (50.0 * 50.0 * 60.0 * 40.0)).abs() < 1e-12);
+    let r = t.independence_test().unwrap();
+    assert_eq!(r.strongest().unwrap().alpha, 0.001);
+    assert_eq!(t.expected()[0][0], 30.0);
+    let weak = ContingencyTable::two_by_two(20.0, 20.0, 22.0, 18.0).unwrap();
+    assert!(weak.independence_test().unwrap().strongest().is_none());
+    let big = ContingencyTable::new(vec![vec![10.0, 20.0, 30.0], vec![20.0, 20.0, 10.0]]).unwrap();
+    assert_eq!(big.degrees_of_freedom(), 2);
+    let s = big.statistic().unwrap();
+    assert!((big.p_value().unwrap() - (-s / 2.0).exp()).abs() < 1e-12);
+    assert!(ContingencyTable::two_by_two(0.0, 0.0, 1.0, 2.0).is_err());
+    // 2×2 表的 K² 与一般公式 Σ(o-e)²/e 一致
+    let obs = [40.0, 10.0, 20.0, 30.0];
+    let e: Vec<f64> = t.expected().into_iter().flatten().collect();
+    let g: f64 = obs.iter().zip(&e).map(|(o, e)| (o - e) * (o - e) / e).sum();
+    assert!((g - k).abs() < 1e-9);
+}
@@ -0,0 +1,44 @@
+use guhs::maths::probability::stats::probability::*;
+
+#[test]
+fn percentiles() {
+    let d = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0];
+    assert_eq!(percentile(0.0, &d), Ok(1.0));
+    assert_eq!(percentile(100.0, &d), Ok(9.0));
+    assert_eq!(percentile(50.0, &d), Ok(3.5));
+    assert_eq!(percentile(25.0, &d), Ok(2.0));
+    assert_eq!(percentile(75.0, &d), Ok(5.0));
+    assert_eq!(percentile(101.0, &d), Err(ProbabilityError::OutOfRange));
+    assert_eq!(percentile(50.0, &[]), Err(ProbabilityError::DntExist));
+    assert_eq!(percentile(100.0, &[7.0]), Ok(7.0));
+    assert_eq!(percentile(50.0, &[7.0]), Ok(7.0));
+    assert_eq!(sample_variance(&[1.0, 2.0, 3.0, 4.0]), Ok(5.0 / 3.0));
+    assert_eq!(population_variance(&[1.0, 2.0, 3.0, 4.0]), Ok(1.25));
+    assert_eq!(sample_variance(&[1.0]), Err(ProbabilityError::TooFew));
+    assert_eq!(weighted_mean(&[80.0, 90.0], &[1.0, 3.0]), Ok(87.5));
+}
+
+#[test]
+fn percentiles_near_the_ends() {
+    let d = [1.0, 2.0, 3.0, 4.0];
+    // n·p/100 舍入到 0 或 n 时不能越界
+    assert_eq!(percentile(1e-12, &d), Ok(1.5));
+    assert_eq!(percentile(100.0 - 1e-12, &d), Ok(3.5));
+    assert_eq!(percentile(1e-3, &d), Ok(1.0));
+    assert_eq!(percentile(100.0 - 1e-3, &d), Ok(4.0));
+    assert_eq!(percentile(1e-12, &[7.0]), Ok(7.0));
+}
+
+#[test]
+fn summary() {
+    let d = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0, 40.0];
+    let s = Summary::new(&d).unwrap();
+    assert_eq!(s.modes, vec![1.0, 3.0, 5.0]);
+    assert_eq!((s.min, s.max, s.range, s.median), (1.0, 40.0, 39.0, 4.0));
+    assert!(s.skewness > 1.0);
+    assert_eq!(Summary::new(&[1.0, 2.0]).unwrap().modes, Vec::<f64>::new());
+    assert_eq!(Summary::new(&[2.0]).unwrap().sample_variance, None);
+    let b = box_plot(&d).unwrap();
+    assert_eq!(b.outliers, vec![40.0]);
+    assert_eq!(b.upper_whisker, 9.0);
+}
@@ -0,0 +1,46 @@
+use guhs::maths::algebra::fraction::fractions::Fraction;
+use guhs::maths::probability::random::random_variables::{expected_value, variance};
+use guhs::maths::probability::sample_space::sample_spaces::*;
+use guhs::maths::probability::variable::discrete_variables::*;
+
+fn fr(a: i128, b: i128) -> Fraction {
+    Fraction::new(a, b).unwrap()
+}
+
+#[test]
+fn table() {
+    let u = SampleSpaceBuilder::new().urn(&[(0, 3), (1, 2)], 2, false, false).build().unwrap();
+    let x = DistributionTable::from_sample_space(&u, |o| o.count(0)).unwrap();
+    assert_eq!(x.probabilities(), vec![fr(1, 10), fr(3, 5), fr(3, 10)]);
+    assert_eq!(x.mean(), Ok(fr(6, 5)));
+    assert_eq!(x.variance(), Ok(fr(9, 25)));
+    let (v, p) = x.to_table();
+    assert!((expected_value(&v, &p).unwrap() - 1.2).abs() < 1e-12);
+    assert!((variance(&v, &p).unwrap() - 0.36).abs() < 1e-12);
+    assert!(!x.to_string().is_empty());
+    let y = x.linear(fr(2, 1), fr(-1, 1)).unwrap();
+    assert_eq!(y.mean(), Ok(fr(7, 5)));
+    assert_eq!(y.variance(), Ok(fr(36, 25)));
+    let d = SampleSpaceBuilder::new().die(6).build().unwrap();
+    let die = DistributionTable::from_sample_space(&d, |o| o.sum()).unwrap();
+    let two = die.add_independent(&die).unwrap();
+    assert_eq!(two.probability(Fraction::from(7)), fr(1, 6));
+    assert_eq!(two.variance(), Ok(fr(35, 6)));
+    assert_eq!(two.probability_where(|v| v >= Fraction::from(11)), Ok(fr(1, 12)));
+    assert_eq!(x.linear(Fraction::zero(), fr(1, 1)).unwrap().entries().len(), 1);
+    assert!(DistributionTable::new(&[(fr(0, 1), fr(1, 2))]).is_err());
+    let signed = DistributionTable::new(&[(fr(-1, 2), fr(1, 3)), (fr(10, 1), fr(2, 3))]).unwrap();
+    assert_eq!(signed.mean(), Ok(fr(13, 2)));
+}
+
+#[test]
+fn overflow_is_reported() {
+    let big = 10i128.pow(30);
+    let x = DistributionTable::new(&[(fr(-big, 1), fr(1, 2)), (fr(big, 1), fr(1, 2))]).unwrap();
+    assert_eq!(x.mean(), Ok(Fraction::zero()));
+    // (x - E(X))² 约为 1e60, 超出 i128
+    assert!(x.variance().is_err());
+    assert!(x.std_dev().is_err());
+    assert!(x.linear(fr(big, 1), Fraction::zero()).is_err());
+    assert_eq!(x.add_independent(&x).unwrap().probability(Fraction::zero()), fr(1, 2));
+}
@@ -0,0 +1,78 @@
+use guhs::maths::probability::distribution::distributions::*;
+use guhs::maths::probability::random::random_variables::{expected_value, variance};
+
+fn close(a: f64, b: f64, t: f64) {
+    assert!((a - b).abs() < t, "{} vs {}", a, b);
+}
+
+#[test]
+fn special() {
+    close(erf(0.5), 0.5204998778130465, 1e-15);
+    close(erf(2.9), 0.9999589021219005, 1e-15);
+    close(erfc(3.5), 7.430983723414128e-7, 1e-20);
+    close(erfc(-1.0), 1.8427007929497148, 1e-15);
+    close(ln_gamma(10.0), 362880f64.ln(), 1e-12);
+    close(ln_gamma(0.5), std::f64::consts::PI.sqrt().ln(), 1e-13);
+    close(standard_normal_quantile(0.975).unwrap(), 1.959963984540054, 1e-12);
+    close(standard_normal_quantile(1e-6).unwrap(), -4.753424308822899, 1e-9);
+    assert!(standard_normal_quantile(1.0).is_err());
+}
+
+#[test]
+fn discrete() {
+    let b = Binomial::new(10, 0.3).unwrap();
+    close(b.pmf(3), 0.2668279320, 1e-9);
+    close(b.cdf(10), 1.0, 1e-12);
+    assert_eq!(b.mode(), vec![3]);
+    assert_eq!(Binomial::new(9, 0.5).unwrap().mode(), vec![4, 5]);
+    assert_eq!(b.quantile(0.5).unwrap(), 3);
+    let (v, p) = b.to_table();
+    close(expected_value(&v, &p).unwrap(), 3.0, 1e-12);
+    close(variance(&v, &p).unwrap(), 2.1, 1e-12);
+    let h = Hypergeometric::new(10, 3, 4).unwrap();
+    close(h.pmf(1), 0.5, 1e-12);
+    close(h.mean(), 1.2, 1e-12);
+    let (v, p) = h.to_table();
+    close(variance(&v, &p).unwrap(), h.variance(), 1e-12);
+    assert_eq!(h.mode(), vec![1]);
+    assert_eq!(h.support(), (0, Some(3)));
+    let g = Geometric::new(0.25).unwrap();
+    let (v, p) = g.to_table();
+    close(expected_value(&v, &p).unwrap(), 4.0, 1e-8);
+    close(g.cdf(2), 0.4375, 1e-12);
+    assert_eq!(g.quantile(0.5).unwrap(), 3);
+    let po = Poisson::new(2.0).unwrap();
+    assert_eq!(po.mode(), vec![1, 2]);
+    close(po.pmf(2), 2.0 * (-2f64).exp(), 1e-14);
+    let (v, p) = po.to_table();
+    close(variance(&v, &p).unwrap(), 2.0, 1e-8);
+    assert!(Binomial::new(3, 1.2).is_err());
+    assert_eq!(Binomial::new(4, 1.0).unwrap().pmf(4), 1.0);
+}
+
+#[test]
+fn degenerate_parameters() {
+    // p = 1 时 (n+1)p = 6 > n, 众数只有 5
+    assert_eq!(Binomial::new(5, 1.0).unwrap().mode(), vec![5]);
+    assert_eq!(Binomial::new(5, 0.0).unwrap().mode(), vec![0]);
+    // (n+1)p = 5.999994 不是整数, 众数为 ⌊(n+1)p⌋ = 5
+    assert_eq!(Binomial::new(5, 0.999999).unwrap().mode(), vec![5]);
+    assert!(Hypergeometric::new(0, 0, 0).is_err());
+    let single = Hypergeometric::new(1, 1, 1).unwrap();
+    assert_eq!(single.variance(), 0.0);
+    assert_eq!(single.mean(), 1.0);
+    close(single.pmf(1), 1.0, 1e-12);
+}
+
+#[test]
+fn normal() {
+    let n = Normal::new(100.0, 15.0).unwrap();
+    close(n.cdf(100.0), 0.5, 1e-15);
+    let r = n.three_sigma_rule();
+    close(r[0].1, 0.6826894921370859, 1e-14);
+    close(r[2].1, 0.9973002039367398, 1e-14);
+    assert_eq!(r[1].0, (70.0, 130.0));
+    close(n.quantile(0.5).unwrap(), 100.0, 1e-9);
+    close(n.interval_probability(85.0, 115.0).unwrap(), r[0].1, 1e-14);
+    close(Normal::standard().pdf(0.0), 0.3989422804014327, 1e-15);
+}
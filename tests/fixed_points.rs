@@ -0,0 +1,29 @@
+use guhs::maths::analytic::conic::conic_sections::*;
+use guhs::maths::analytic::fixed::fixed_points::*;
+use guhs::maths::analytic::line::lines::Line;
+
+#[test]
+fn perpendicular_chords_pass_fixed_point() {
+    // y² = 4x, OA ⊥ OB 时直线 AB 恒过 (4,0)
+    let params = sample_params(0.3, 5.0, 20);
+    let r = fixed_point_of_chords(|k| Ok(((4.0 / (k * k), 4.0 / k), (4.0 * k * k, -4.0 * k))), &params).unwrap();
+    assert!(r.is_fixed, "{:?}", r);
+    assert!((r.point.0 - 4.0).abs() < 1e-6 && r.point.1.abs() < 1e-6);
+}
+
+#[test]
+fn not_fixed_and_constant() {
+    let params = sample_params(-2.0, 2.0, 9);
+    let r = fixed_point_of_lines(|k| Ok(Line::slope_intercept(k, k * k)), &params).unwrap();
+    assert!(!r.is_fixed);
+    assert!(fixed_point_of_lines(|k| Ok(Line::slope_intercept(1.0, k)), &params).is_err());
+    // x²/4 + y²/3 = 1 过焦点 F(1,0) 的弦: 1/|AF| + 1/|BF| = 2a/b² = 4/3
+    let e = Ellipse::new(2.0, 3f64.sqrt()).unwrap();
+    let reciprocal_sum = |k: f64| {
+        let ch = line_conic_intersection(&e, &Line::point_slope(1.0, 0.0, k))?;
+        let d: Vec<f64> = ch.points.iter().map(|p| (p.0 - 1.0).hypot(p.1)).collect();
+        Ok(1.0 / d[0] + 1.0 / d[1])
+    };
+    let c = constant_value(reciprocal_sum, &sample_params(-3.0, 3.0, 13)).unwrap();
+    assert!(c.is_constant && (c.value - 4.0 / 3.0).abs() < 1e-9, "{:?}", c);
+}
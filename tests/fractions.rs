@@ -0,0 +1,31 @@
+use guhs::maths::algebra::fraction::fractions::Fraction;
+use std::cmp::Ordering;
+
+#[test]
+fn checked_arithmetic() {
+    let big = Fraction::from_integer(i128::MAX / 2 + 1);
+    assert!(big.checked_add(big).is_err());
+    assert!(big.checked_mul(Fraction::from(2)).is_err());
+    assert!(big.checked_sub(-big).is_err());
+    assert_eq!(big.checked_mul(Fraction::new(1, 2).unwrap()), Ok(Fraction::from_integer(i128::MAX / 4 + 1)));
+    assert!(Fraction::from(2).powi(127).is_err());
+    assert_eq!(Fraction::from(2).powi(-3), Fraction::new(1, 8));
+    let third = Fraction::new(1, 3).unwrap();
+    assert_eq!(third.checked_add(third), Fraction::new(2, 3));
+    assert!(third.checked_div(Fraction::zero()).is_err());
+}
+
+#[test]
+fn comparison_never_overflows() {
+    // 交叉相乘超出 i128 时仍能精确比较
+    let p = i128::MAX / 3;
+    let a = Fraction::new(p, p - 1).unwrap();
+    let b = Fraction::new(p - 1, p - 2).unwrap();
+    assert_eq!(a.cmp(&b), Ordering::Less);
+    assert_eq!(b.cmp(&a), Ordering::Greater);
+    assert_eq!(a.cmp(&a), Ordering::Equal);
+    assert!(-b < -a);
+    let c = Fraction::new(i128::MAX - 1, i128::MAX).unwrap();
+    assert!(c < Fraction::one() && Fraction::zero() < c);
+    assert!(Fraction::new(-p, p - 1).unwrap() < Fraction::new(-(p - 1), p - 2).unwrap().abs());
+}
@@ -0,0 +1,68 @@
+use guhs::maths::discrete::counting::combinatorics::*;
+use guhs::maths::discrete::generators::generators::*;
+
+fn sorted_strictly<T: Ord>(v: &[Vec<T>]) -> bool {
+    v.windows(2).all(|w| w[0] < w[1])
+}
+
+#[test]
+fn iterators() {
+    let idx: Vec<usize> = (0..5).collect();
+    for k in 0..=5 {
+        let p: Vec<_> = Permutations::new(&idx, k).unwrap().collect();
+        assert_eq!(p.len() as u64, permutation(5, k as u64).unwrap());
+        assert!(sorted_strictly(&p));
+        for (r, x) in p.iter().enumerate() {
+            assert_eq!(permutation_rank(x, 5).unwrap(), r as u128);
+            assert_eq!(&permutation_unrank(5, k, r as u128).unwrap(), x);
+        }
+        let c: Vec<_> = Combinations::new(&idx, k).unwrap().collect();
+        assert_eq!(c.len() as u64, combination(5, k as u64).unwrap());
+        assert!(sorted_strictly(&c));
+        for (r, x) in c.iter().enumerate() {
+            assert_eq!(combination_rank(x, 5).unwrap(), r as u128);
+            assert_eq!(&combination_unrank(5, k, r as u128).unwrap(), x);
+        }
+        let m: Vec<_> = CombinationsWithReplacement::new(&idx, k).collect();
+        assert_eq!(m.len() as u64, combination(5 + k as u64 - 1, k as u64).unwrap_or(1));
+        assert!(sorted_strictly(&m));
+        for (r, x) in m.iter().enumerate() {
+            assert_eq!(multicombination_rank(x, 5).unwrap(), r as u128);
+            assert_eq!(&multicombination_unrank(5, k, r as u128).unwrap(), x);
+        }
+    }
+    let s: Vec<_> = Subsets::new(&idx).collect();
+    assert_eq!(s.len(), 32);
+    assert!(sorted_strictly(&s));
+    for (r, x) in s.iter().enumerate() {
+        assert_eq!(subset_rank(x, 5).unwrap(), r as u128);
+        assert_eq!(&subset_unrank(5, r as u128).unwrap(), x);
+    }
+    assert!(subset_unrank(5, 32).is_err());
+    assert_eq!(Subsets::<u8>::new(&[]).count(), 1);
+    let word: Vec<char> = "banana".chars().collect();
+    let mp: Vec<_> = MultisetPermutations::new(&word).collect();
+    assert_eq!(mp.len(), 60);
+    assert!(sorted_strictly(&mp));
+    for (r, x) in mp.iter().enumerate() {
+        assert_eq!(multiset_permutation_rank(&word, x).unwrap(), r as u128);
+        assert_eq!(&multiset_permutation_unrank(&word, r as u128).unwrap(), x);
+    }
+    let other: Vec<char> = "bananb".chars().collect();
+    assert!(multiset_permutation_rank(&word, &other).is_err());
+    assert!(multiset_permutation_rank(&word, &word[..5]).is_err());
+    assert_eq!(multinomial(&[3, 2, 1]), Some(60));
+    assert!(combination_unrank(5, 2, 10).is_err());
+    assert!(Permutations::new(&idx, 6).is_err());
+    assert_eq!(binomial(100, 50), Some(100891344545564193334812497256));
+}
+
+#[test]
+fn counting_shares_checked_helpers() {
+    assert_eq!(permutation(20, 20).unwrap() as u128, falling_factorial(20, 20).unwrap());
+    assert_eq!(combination(66, 33).unwrap() as u128, binomial(66, 33).unwrap());
+    // 超出 u64 时报错而不是溢出
+    assert!(permutation(21, 21).is_err());
+    assert!(combination(68, 34).is_err());
+    assert!(binomial(200, 100).is_none());
+}
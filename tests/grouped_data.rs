@@ -0,0 +1,20 @@
+use guhs::maths::probability::stats::probability::*;
+
+#[test]
+fn grouped() {
+    let edges = [40.0, 50.0, 60.0, 70.0, 80.0, 90.0, 100.0];
+    let heights = [0.010, 0.015, 0.015, 0.030, 0.025, 0.005];
+    let g = GroupedData::from_heights(&edges, &heights).unwrap();
+    assert!((g.mean() - 71.0).abs() < 1e-9);
+    assert!((g.median() - 73.3333333333).abs() < 1e-6);
+    assert_eq!(g.modes(), vec![75.0]);
+    assert!((g.percentile(0.0).unwrap() - 40.0).abs() < 1e-12);
+    assert!((g.percentile(100.0).unwrap() - 100.0).abs() < 1e-12);
+    assert!((g.percentile(80.0).unwrap() - 84.0).abs() < 1e-9);
+    // 文本直方图每组一行
+    assert_eq!(g.to_string().lines().filter(|l| l.contains('|')).count(), 6);
+    assert!(GroupedData::from_heights(&edges, &[0.01; 6]).is_err());
+    let c = GroupedData::from_counts(&[0.0, 10.0, 20.0], &[3.0, 7.0]).unwrap();
+    assert_eq!(c.counts(), Some(vec![3.0, 7.0]));
+    assert_eq!(GroupedData::from_counts(&[0.0, 10.0, 5.0], &[3.0, 7.0]), Err(ProbabilityError::InvalidIntervals));
+}
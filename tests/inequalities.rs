@@ -0,0 +1,17 @@
+use guhs::maths::calculus::inequality::analysis::*;
+
+#[test]
+fn jensen_respects_convexity() {
+    let points = [1.0, 2.0, 3.0, 6.0];
+    let (f_mean, mean_f) = jensen_inequality(|x| x * x, true, &points).unwrap();
+    assert!((f_mean - 9.0).abs() < 1e-12 && (mean_f - 12.5).abs() < 1e-12);
+    let (f_mean, mean_f) = jensen_inequality(f64::ln, false, &points).unwrap();
+    assert!(f_mean >= mean_f);
+    // 凹凸性声明与函数不符
+    assert!(jensen_inequality(|x| x * x, false, &points).is_err());
+    assert!(jensen_inequality(f64::ln, true, &points).is_err());
+    // 线性函数两种声明都成立
+    assert!(jensen_inequality(|x| 3.0 * x + 1.0, true, &points).is_ok());
+    assert!(jensen_inequality(|x| 3.0 * x + 1.0, false, &points).is_ok());
+    assert!(jensen_inequality(|x| x, true, &[]).is_err());
+}
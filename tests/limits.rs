@@ -0,0 +1,96 @@
+use guhs::maths::algebra::sequence::sequences::*;
+use guhs::maths::calculus::limits::limits::*;
+
+fn conv(b: LimitBehavior) -> f64 {
+    match b {
+        LimitBehavior::Converges { limit, .. } => limit,
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn limits() {
+    let e = ClosureSequence::new(|n| (1.0 + 1.0 / n as f64).powi(n as i32));
+    let l = conv(estimate_limit(&e, 200).unwrap());
+    assert!((l - std::f64::consts::E).abs() < 1e-6, "{}", l);
+    let inv = ClosureSequence::new(|n| 3.0 + 1.0 / n as f64);
+    assert!((conv(estimate_limit(&inv, 64).unwrap()) - 3.0).abs() < 1e-8);
+    let alt = ClosureSequence::new(|n| if n % 2 == 0 { 1.0 } else { -1.0 } / n as f64);
+    assert!(conv(estimate_limit(&alt, 100).unwrap()).abs() < 1e-6);
+    let osc = ClosureSequence::new(|n| if n % 2 == 0 { 1.0 } else { -1.0 });
+    assert_eq!(estimate_limit(&osc, 50).unwrap(), LimitBehavior::Oscillates { lower: -1.0, upper: 1.0 });
+    let ln = ClosureSequence::new(|n| (n as f64).ln());
+    assert_eq!(estimate_limit(&ln, 500).unwrap(), LimitBehavior::DivergesToInfinity);
+    let g = GeometricSequence::new(1.0, 2.0).unwrap();
+    assert_eq!(estimate_limit(&g, 30).unwrap(), LimitBehavior::DivergesToInfinity);
+    let g2 = GeometricSequence::new(1.0, -2.0).unwrap();
+    assert_eq!(estimate_limit(&g2, 30).unwrap(), LimitBehavior::Oscillates { lower: f64::NEG_INFINITY, upper: f64::INFINITY });
+    let un = ClosureSequence::new(|n| if n % 2 == 0 { n as f64 } else { -(n as f64) });
+    assert_eq!(estimate_limit(&un, 40).unwrap(), LimitBehavior::Oscillates { lower: f64::NEG_INFINITY, upper: f64::INFINITY });
+    // a_(n+1) = a_n / 2 趋于 0
+    assert!(conv(recurrence_limit(&[1.0], &[0.5], 40).unwrap()).abs() < 1e-9);
+    let fibratio = ClosureSequence::new(|n| {
+        let s = RecurrenceSequence::new(&[1.0, 1.0], &[1.0, 1.0]).unwrap();
+        s.term(n + 1).unwrap() / s.term(n).unwrap()
+    });
+    assert!((conv(estimate_limit(&fibratio, 40).unwrap()) - 1.618033988749895).abs() < 1e-9);
+    let seq = GeometricSequence::new(1.0, 0.5).unwrap();
+    assert!((conv(estimate_limit(&seq.into_partial_sums(), 40).unwrap()) - 2.0).abs() < 1e-9);
+}
+
+#[test]
+fn iteration() {
+    let f = |x: f64| x.cos();
+    let a = iteration_analysis(&f, 1.0, -2.0, 2.0, 100).unwrap();
+    assert_eq!(a.fixed_points.len(), 1);
+    assert_eq!(a.attracted_to.unwrap().stability, Stability::Attracting);
+    let logistic = |x: f64| 3.2 * x * (1.0 - x);
+    let b = iteration_analysis(&logistic, 0.3, -0.5, 1.0, 200).unwrap();
+    assert!(matches!(b.orbit, LimitBehavior::Oscillates { .. }));
+    assert!(b.fixed_points.iter().all(|p| p.stability == Stability::Repelling));
+    let sqrt2 = |x: f64| (x + 2.0 / x) / 2.0;
+    let c = iteration_analysis(&sqrt2, 1.0, 0.5, 3.0, 20).unwrap();
+    assert!((c.attracted_to.unwrap().x - 2f64.sqrt()).abs() < 1e-9);
+}
+
+#[test]
+fn series() {
+    let fact = ClosureSequence::new(|n| 1.0 / (1..=n).map(|k| k as f64).product::<f64>());
+    assert_eq!(ratio_test(&fact, 30).unwrap().verdict, SeriesVerdict::Converges);
+    let pow = ClosureSequence::new(|n| (n as f64) / 2f64.powi(n as i32));
+    let t = ratio_test(&pow, 60).unwrap();
+    assert!((t.statistic - 0.5).abs() < 1e-6 && t.verdict == SeriesVerdict::Converges);
+    let harmonic = ClosureSequence::new(|n| 1.0 / n as f64);
+    assert_eq!(ratio_test(&harmonic, 100).unwrap().verdict, SeriesVerdict::Inconclusive);
+    let r = root_test(&ClosureSequence::new(|n| (2.0 / 3.0f64).powi(n as i32) * n as f64), 200).unwrap();
+    assert_eq!(r.verdict, SeriesVerdict::Converges);
+    let big = ClosureSequence::new(|n| 3f64.powi(n as i32) / (n as f64));
+    assert_eq!(ratio_test(&big, 30).unwrap().verdict, SeriesVerdict::Diverges);
+    let a = ClosureSequence::new(|n| 1.0 / (n as f64 * n as f64 + n as f64));
+    let b = ClosureSequence::new(|n| 1.0 / (n as f64 * n as f64));
+    let c = comparison_test(&a, &b, true, 100).unwrap();
+    assert_eq!(c.verdict, SeriesVerdict::Converges);
+    let d = ClosureSequence::new(|n| 1.0 / (2.0 * n as f64 - 1.0));
+    assert_eq!(comparison_test(&d, &harmonic, false, 100).unwrap().verdict, SeriesVerdict::Diverges);
+    assert!((richardson(&ClosureSequence::new(|n| 1.0 + 1.0 / n as f64 + 1.0 / (n * n) as f64), 4, 3).unwrap() - 1.0).abs() < 1e-12);
+    assert_eq!(aitken(&[1.0, 0.5, 0.25, 0.125]), vec![0.0, 0.0]);
+}
+
+#[test]
+fn slow_geometric_convergence() {
+    // 1 - 0.99^n: 增量之比恒为 0.99, 尾部衰减比 0.99^50 ≈ 0.6 也应判为收敛
+    let slow: Vec<f64> = (1..=100).map(|n| 1.0 - 0.99f64.powi(n)).collect();
+    assert!((conv(estimate_limit_from_terms(&slow).unwrap()) - 1.0).abs() < 1e-9);
+    let short: Vec<f64> = (1..=8).map(|n| 1.0 - 0.9f64.powi(n)).collect();
+    assert!((conv(estimate_limit_from_terms(&short).unwrap()) - 1.0).abs() < 1e-9);
+    let falling: Vec<f64> = (1..=60).map(|n| 2.0 + 0.95f64.powi(n)).collect();
+    assert!((conv(estimate_limit_from_terms(&falling).unwrap()) - 2.0).abs() < 1e-9);
+    // 调和级数部分和的增量之比也小于 1, 但并非常数
+    let harmonic: Vec<f64> = (1..=400)
+        .scan(0.0, |s, n| {
+            *s += 1.0 / n as f64;
+            Some(*s)
+        })
+        .collect();
+    assert_eq!(estimate_limit_from_terms(&harmonic).unwrap(), LimitBehavior::DivergesToInfinity);
+}
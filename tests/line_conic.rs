@@ -0,0 +1,60 @@
+use guhs::maths::analytic::conic::conic_sections::*;
+use guhs::maths::analytic::line::lines::Line;
+
+#[test]
+fn ellipse_chord() {
+    // x²/4 + y² = 1, y = x + 1 → 5x² + 8x = 0
+    let e = Ellipse::new(2.0, 1.0).unwrap();
+    let r = line_conic_intersection(&e, &Line::slope_intercept(1.0, 1.0)).unwrap();
+    assert_eq!(r.position, LinePosition::Secant);
+    assert!((r.root_sum.unwrap() + 1.6).abs() < 1e-12);
+    assert!((r.chord_length.unwrap() - 2f64.sqrt() * 1.6).abs() < 1e-12);
+    let (mx, my) = r.midpoint.unwrap();
+    assert!((mx + 0.8).abs() < 1e-12 && (my - 0.2).abs() < 1e-12);
+    // A(0,1), B(-1.6,-0.6): OA·OB = -0.6
+    assert!((r.dot_product.unwrap() + 0.6).abs() < 1e-12, "{:?}", r);
+}
+
+#[test]
+fn tangent_and_single() {
+    let e = Ellipse::new(2.0, 1.0).unwrap();
+    let r = line_conic_intersection(&e, &Line::vertical(2.0)).unwrap();
+    assert_eq!(r.position, LinePosition::Tangent);
+    assert_eq!(r.points.len(), 1);
+    let h = Hyperbola::new(1.0, 2.0).unwrap();
+    let r = line_conic_intersection(&h, &Line::slope_intercept(2.0, 1.0)).unwrap();
+    assert_eq!(r.position, LinePosition::SinglePoint);
+    let p = Parabola::new(2.0).unwrap();
+    let r = line_conic_intersection(&p, &Line::slope_intercept(0.0, 2.0)).unwrap();
+    assert_eq!(r.position, LinePosition::SinglePoint);
+    assert!((r.points[0].0 - 1.0).abs() < 1e-12);
+    // y²=4x 过焦点 (1,0) 的垂直弦, 弦长 2p = 4
+    let r = line_conic_intersection(&p, &Line::vertical(1.0)).unwrap();
+    assert!((r.chord_length.unwrap() - 4.0).abs() < 1e-12);
+    assert!((r.dot_product.unwrap() + 3.0).abs() < 1e-12);
+    let r = line_conic_intersection(&e, &Line::slope_intercept(0.0, 3.0)).unwrap();
+    assert_eq!(r.position, LinePosition::Separate);
+    assert!((point_difference_method_parabola(2.0, 2.0).unwrap() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn line_contains_uses_module_tolerance() {
+    let l = Line::slope_intercept(1.0, 1.0);
+    assert!(l.contains(1.0, 2.0));
+    assert!(!l.contains(1.0, 2.0 + 1e-8));
+    // 坐标在 1e6 量级时, 直线上的点仍应判定在线上
+    let l = Line::through_points(1e6 + 0.1, 2e6 + 0.3, -3e6 + 0.7, 1e6 + 0.9).unwrap();
+    for i in 0..=10 {
+        let t = i as f64 / 10.0;
+        assert!(l.contains(1e6 + 0.1 + t * (-4e6 + 0.6), 2e6 + 0.3 + t * (-1e6 + 0.6)), "t = {}", t);
+    }
+    assert!(!l.contains(1e6 + 0.1, 2e6 + 0.31));
+}
+
+#[test]
+fn ellipse_midpoint_slope() {
+    // x²/4 + y² = 1 上以 (1, 1/2) 为中点的弦斜率为 -1/2
+    assert!((point_difference_method_ellipse(1.0, 0.5, 2.0, 1.0).unwrap() + 0.5).abs() < 1e-12);
+    assert!(point_difference_method_ellipse(0.0, 0.5, 2.0, 1.0).is_ok());
+    assert!(point_difference_method_ellipse(1.0, 0.0, 2.0, 1.0).is_err());
+}
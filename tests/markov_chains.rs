@@ -0,0 +1,62 @@
+use guhs::maths::algebra::fraction::fractions::Fraction;
+use guhs::maths::probability::markov::markov_chains::*;
+
+fn fr(a: i128, b: i128) -> Fraction {
+    Fraction::new(a, b).unwrap()
+}
+
+#[test]
+fn ball_passing() {
+    // 三人传球, 每次等可能传给另外两人, 从A开始, 传4次后回到A的概率 = 3/8
+    let z = Fraction::zero();
+    let h = fr(1, 2);
+    let m = MarkovChain::new(vec![vec![z, h, h], vec![h, z, h], vec![h, h, z]]).unwrap();
+    let d = m.distribution_after(&[fr(1, 1), z, z], 4).unwrap();
+    assert_eq!(d[0], fr(3, 8));
+    assert_eq!(m.stationary().unwrap(), vec![fr(1, 3); 3]);
+    assert_eq!(m.expected_hitting_times(&[0]).unwrap(), vec![z, fr(2, 1), fr(2, 1)]);
+    assert_eq!(m.step(&[fr(1, 1), z, z]).unwrap(), vec![z, h, h]);
+    assert!(m.absorption().is_err());
+    let f = MarkovChain::new(vec![vec![0.9, 0.1], vec![0.5, 0.5]]).unwrap();
+    let s = f.stationary().unwrap();
+    assert!((s[0] - 5.0 / 6.0).abs() < 1e-12);
+    assert!(MarkovChain::new(vec![vec![0.9, 0.2], vec![0.5, 0.5]]).is_err());
+    let id = MarkovChain::new(vec![vec![1.0, 0.0], vec![0.0, 1.0]]).unwrap();
+    assert!(id.stationary().is_err());
+}
+
+#[test]
+fn walks() {
+    let w = RandomWalk::new(fr(1, 2)).unwrap();
+    let (ruin, steps) = w.ruin(0, 10, 3).unwrap();
+    assert_eq!(ruin, fr(7, 10));
+    assert_eq!(steps, fr(21, 1));
+    let w2 = RandomWalk::new(fr(2, 3)).unwrap();
+    let (ruin, _) = w2.ruin(0, 3, 1).unwrap();
+    // q/p = 1/2: P(ruin from 1) = ((1/2)^1 - (1/2)^3)/(1 - (1/2)^3) = (3/8)/(7/8) = 3/7
+    assert_eq!(ruin, fr(3, 7));
+    let d = w.position_distribution(2).unwrap();
+    assert_eq!(d, vec![(-2, fr(1, 4)), (0, fr(1, 2)), (2, fr(1, 4))]);
+    let a = w.with_barriers(0, 4).unwrap().absorption().unwrap();
+    assert_eq!(a.probabilities[1], vec![fr(1, 2), fr(1, 2)]);
+    assert_eq!(w.ruin(0, 4, 0).unwrap(), (fr(1, 1), Fraction::zero()));
+    let f = RandomWalk::new(0.5).unwrap();
+    let (r, s) = f.ruin(0, 10, 3).unwrap();
+    assert!((r - 0.7).abs() < 1e-12 && (s - 21.0).abs() < 1e-9);
+}
+
+#[test]
+fn long_runs() {
+    // P^n 的元素为 (1 ± (-1/3)^n)/2, 分母为 2·3^n
+    let m = MarkovChain::new(vec![vec![fr(1, 3), fr(2, 3)], vec![fr(2, 3), fr(1, 3)]]).unwrap();
+    let d = m.distribution_after(&[fr(1, 1), Fraction::zero()], 64).unwrap();
+    let third = fr(1, 3).powi(64).unwrap();
+    assert_eq!(d, vec![(fr(1, 1) + third) / fr(2, 1), (fr(1, 1) - third) / fr(2, 1)]);
+    // 3^100 超出 i128
+    assert!(m.n_step_matrix(100).is_err());
+    assert!(m.distribution_after(&[fr(1, 1), Fraction::zero()], 100).is_err());
+    assert!(RandomWalk::new(fr(1, 3)).unwrap().position_distribution(100).is_err());
+    let f = MarkovChain::new(vec![vec![1.0 / 3.0, 2.0 / 3.0], vec![2.0 / 3.0, 1.0 / 3.0]]).unwrap();
+    let d = f.distribution_after(&[1.0, 0.0], 1000).unwrap();
+    assert!((d[0] - 0.5).abs() < 1e-12);
+}
@@ -0,0 +1,48 @@
+use guhs::maths::probability::distribution::distributions::*;
+use guhs::maths::probability::regression::statistics::*;
+
+#[test]
+fn t_dist() {
+    assert!((student_t_cdf(0.0, 5) - 0.5).abs() < 1e-12);
+    assert!((student_t_quantile(0.975, 10).unwrap() - 2.228).abs() < 1e-3);
+    assert!((student_t_quantile(0.95, 1).unwrap() - 6.314).abs() < 1e-3);
+    assert!((student_t_quantile(0.975, 1000).unwrap() - 1.962).abs() < 1e-3);
+    assert!((student_t_cdf(-2.015, 5) - 0.05).abs() < 1e-3);
+    assert!((regularized_beta(2.0, 3.0, 0.4) - 0.5248).abs() < 1e-4);
+}
+
+#[test]
+fn multiple() {
+    let x1 = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+    let x2 = vec![2.0, 1.0, 4.0, 3.0, 6.0, 5.0, 8.0, 9.0];
+    let noise = [0.1, -0.1, 0.05, -0.05, 0.1, -0.1, 0.0, 0.02];
+    let y: Vec<f64> = (0..8).map(|i| 1.0 + 2.0 * x1[i] - 0.5 * x2[i] + noise[i]).collect();
+    let r = multiple_regression(&[x1.clone(), x2.clone()], &y).unwrap();
+    assert!((r.coefficients[1] - 2.0).abs() < 0.1 && (r.coefficients[2] + 0.5).abs() < 0.1, "{:?}", r);
+    assert!(r.r_squared > 0.99 && r.adjusted_r_squared < r.r_squared);
+    assert_eq!(r.df, 5);
+    assert!(r.p_values[1] < 1e-4);
+    let (lo, hi) = r.prediction_interval(&[4.5, 4.5], 0.95).unwrap();
+    let (clo, chi) = r.confidence_interval(&[4.5, 4.5], 0.95).unwrap();
+    assert!(lo < clo && chi < hi);
+    // 与一元最小二乘一致
+    let one = multiple_regression(std::slice::from_ref(&x1), &y).unwrap();
+    let (slope, intercept) = least_squares(&x1, &y).unwrap();
+    assert!((one.coefficients[1] - slope).abs() < 1e-9 && (one.coefficients[0] - intercept).abs() < 1e-9);
+    let dup: Vec<f64> = x1.iter().map(|v| 2.0 * v).collect();
+    assert!(multiple_regression(&[x1.clone(), dup], &y).is_err());
+    assert!(multiple_regression(std::slice::from_ref(&x1), &y[..7]).is_err());
+    assert!(r.predict(&[1.0]).is_err());
+}
+
+#[test]
+fn small_scale_predictors_are_not_collinear() {
+    // 自变量以 1e-6 为单位时, 共线性判断不应受量纲影响
+    let x1: Vec<f64> = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0].iter().map(|v| v * 1e-6).collect();
+    let x2: Vec<f64> = [2.0, 1.0, 4.0, 3.0, 6.0, 4.0].iter().map(|v| v * 1e-6).collect();
+    let y: Vec<f64> = (0..6).map(|i| 1.0 + 2e6 * x1[i] + 3e6 * x2[i] + if i % 2 == 0 { 0.01 } else { -0.01 }).collect();
+    let r = multiple_regression(&[x1.clone(), x2], &y).unwrap();
+    assert!((r.coefficients[1] - 2e6).abs() < 1e5 && (r.coefficients[2] - 3e6).abs() < 1e5, "{:?}", r.coefficients);
+    let dup: Vec<f64> = x1.iter().map(|v| 3.0 * v).collect();
+    assert!(multiple_regression(&[x1, dup], &y).is_err());
+}
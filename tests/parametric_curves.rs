@@ -0,0 +1,51 @@
+use guhs::maths::analytic::conic::conic_sections::*;
+use guhs::maths::calculus::parametric::parametric_curves::*;
+use std::f64::consts::PI;
+
+#[test]
+fn curves() {
+    let circle = |t: f64| -> Result<(f64, f64), &'static str> { Ok((2.0 * t.cos(), 2.0 * t.sin())) };
+    let l = arc_length(&circle, 0.0, 2.0 * PI).unwrap();
+    assert!((l - 4.0 * PI).abs() < 1e-8, "{}", l);
+    let k = curvature(&circle, 1.0).unwrap();
+    assert!((k - 0.5).abs() < 1e-6, "{}", k);
+    let (tx, ty) = unit_tangent(&circle, 0.0).unwrap();
+    assert!(tx.abs() < 1e-9 && (ty - 1.0).abs() < 1e-9);
+    let (nx, _) = unit_normal(&circle, 0.0).unwrap();
+    assert!((nx + 1.0).abs() < 1e-9);
+    let tl = tangent_line(&circle, 0.0).unwrap();
+    assert!(tl.contains(2.0, 5.0));
+    let cp = closest_point(&circle, 3.0, 3.0, 0.0, 2.0 * PI, 100).unwrap();
+    assert!((cp.t - PI / 4.0).abs() < 1e-6 && (cp.distance - (18f64.sqrt() - 2.0)).abs() < 1e-9, "{:?}", cp);
+    let ell = |t: f64| parametric_ellipse(2.0, 1.0, t);
+    let k = curvature(&ell, 0.0).unwrap();
+    assert!((k - 2.0).abs() < 1e-5, "{}", k); // 长轴端点处曲率 a/b² = 2
+    let hyp = |t: f64| parametric_hyperbola(1.0, 1.0, t);
+    assert!(parametric_hyperbola(1.0, 1.0, PI / 2.0).is_err());
+    assert!(tangent_line(&hyp, PI / 2.0).is_err());
+    assert!(arc_length(&hyp, 0.0, 2.0).is_err());
+    assert!(arc_length(&hyp, 0.0, 1.0).is_ok());
+    let par = |t: f64| parametric_parabola(1.0, t);
+    assert!(curvature(&par, 0.0).is_ok());
+    let cusp = |t: f64| -> Result<(f64, f64), &'static str> { Ok((t * t, t * t * t)) };
+    assert!(unit_tangent(&cusp, 0.0).is_err());
+}
+
+#[test]
+fn closest_point_far_along_parameter() {
+    let line = |t: f64| -> Result<(f64, f64), &'static str> { Ok((t, 0.0)) };
+    let cp = closest_point(&line, 1e6 + 0.25, 1.0, 1e6 - 1.0, 1e6 + 1.0, 5).unwrap();
+    assert!((cp.t - (1e6 + 0.25)).abs() < 1e-6, "{:?}", cp);
+    assert!((cp.distance - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn arc_length_scales_with_the_curve() {
+    // 半径 1e6 的圆上参数区间 [0, 1] 的弧长为 1e6
+    let big = |t: f64| -> Result<(f64, f64), &'static str> { Ok((1e6 * t.cos(), 1e6 * t.sin())) };
+    let l = arc_length(&big, 0.0, 1.0).unwrap();
+    assert!((l - 1e6).abs() < 1e-3, "{}", l);
+    let small = |t: f64| -> Result<(f64, f64), &'static str> { Ok((1e-3 * t.cos(), 1e-3 * t.sin())) };
+    let l = arc_length(&small, 0.0, 2.0 * PI).unwrap();
+    assert!((l - 2e-3 * PI).abs() < 1e-12, "{}", l);
+}
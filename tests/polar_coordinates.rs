@@ -0,0 +1,63 @@
+use guhs::maths::analytic::conic::conic_sections::*;
+use guhs::maths::analytic::line::lines::Line;
+use guhs::maths::analytic::polar::polar_coordinates::*;
+
+#[test]
+fn lines_and_circles() {
+    let (r, t) = cartesian_to_polar(-1.0, -1.0);
+    assert!((r - 2f64.sqrt()).abs() < 1e-12 && (t - 1.25 * std::f64::consts::PI).abs() < 1e-12);
+    let l = Line::new(1.0, 1.0, -2.0).unwrap();
+    let pl = line_to_polar(&l);
+    assert!((pl.d - 2f64.sqrt()).abs() < 1e-12);
+    let rho = pl.rho(0.0).unwrap();
+    assert!((rho - 2.0).abs() < 1e-12);
+    let l2 = polar_to_line(&pl);
+    assert!(l2.contains(2.0, 0.0) && l2.contains(0.0, 2.0));
+    let l = Line::new(1.0, 1.0, 2.0).unwrap();
+    let pl = line_to_polar(&l);
+    let (x, y) = polar_to_cartesian(pl.rho(pl.alpha).unwrap(), pl.alpha);
+    assert!(l.contains(x, y));
+    // (x-1)² + y² = 1 即 ρ = 2cosθ
+    let c = circle_to_polar(1.0, 0.0, 1.0).unwrap();
+    assert!(c.passes_through_pole());
+    let rs = c.rho(0.5);
+    assert!(rs.iter().any(|r| (r - 2.0 * 0.5f64.cos()).abs() < 1e-12), "{:?}", rs);
+}
+
+#[test]
+fn conics_with_focus_at_pole() {
+    // a = 2, b = √3, c = 1, 极坐标方程给出的 ρ 即焦半径
+    let e = Ellipse::new(2.0, 3f64.sqrt()).unwrap();
+    let pc = ellipse_to_polar(&e);
+    for t in [0.0, 1.0, 2.0, 3.0] {
+        let r = pc.rho(t).unwrap();
+        assert!((r - focal_radius_ellipse(2.0, 0.5, t).unwrap()).abs() < 1e-12);
+        let (x, y) = polar_to_cartesian(r, t);
+        assert!(e.general_form().value(x - 1.0, y).abs() < 1e-12);
+    }
+    // 通径 2b²/a = 3
+    assert!((pc.focal_chord(std::f64::consts::FRAC_PI_2).unwrap() - 3.0).abs() < 1e-12);
+    match pc.to_canonical() {
+        CanonicalForm::Ellipse(back) => assert!((back.a - 2.0).abs() < 1e-12 && (back.b - 3f64.sqrt()).abs() < 1e-12),
+        other => panic!("{:?}", other),
+    }
+    let h = Hyperbola::new(1.0, 1.0).unwrap();
+    let ph = hyperbola_to_polar(&h);
+    let r = ph.rho(2.5).unwrap();
+    let (x, y) = polar_to_cartesian(r, 2.5);
+    assert!(h.general_form().value(x + 2f64.sqrt(), y).abs() < 1e-9);
+    let pp = parabola_to_polar(&Parabola::new(2.0).unwrap());
+    assert!((pp.focal_chord(std::f64::consts::FRAC_PI_2).unwrap() - 4.0).abs() < 1e-12);
+}
+
+#[test]
+fn parametric_focal_chord() {
+    let pp = parabola_to_polar(&Parabola::new(2.0).unwrap());
+    // y² = 4x, 过焦点 (1,0) 倾斜角 45° 的弦
+    let ch = parametric_chord(&Parabola::new(2.0).unwrap(), 1.0, 0.0, 0.5f64.sqrt(), 0.5f64.sqrt()).unwrap();
+    assert!((ch.chord_length - 8.0).abs() < 1e-9, "{:?}", ch);
+    assert!((ch.chord_length - pp.focal_chord(std::f64::consts::FRAC_PI_4).unwrap()).abs() < 1e-9);
+    let ch2 = parametric_chord(&Parabola::new(2.0).unwrap(), 1.0, 0.0, 1.0, 1.0).unwrap();
+    assert!((ch2.chord_length - 8.0).abs() < 1e-9);
+    assert!((ch2.distance_sum - 8.0).abs() < 1e-9);
+}
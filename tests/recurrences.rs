@@ -0,0 +1,95 @@
+use guhs::maths::algebra::sequence::sequences::*;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-6 * b.abs().max(1.0)
+}
+
+// 通项公式与直接递推的前若干项逐项比较
+fn check_homogeneous(initial: &[f64], coefficients: &[f64], terms: usize) {
+    let cf = recurrence_closed_form(initial, coefficients).unwrap();
+    for n in 0..terms {
+        let expected = recurrence_term(initial, coefficients, n).unwrap();
+        assert!(close(cf.evaluate(n), expected), "n = {}: {}", n, cf);
+    }
+}
+
+#[test]
+fn homogeneous_closed_forms() {
+    // 斐波那契数列
+    check_homogeneous(&[0.0, 1.0], &[1.0, 1.0], 30);
+    // 二重根: a_n = 4a_(n-1) - 4a_(n-2)
+    check_homogeneous(&[1.0, 4.0], &[4.0, -4.0], 20);
+    // 复根: a_n = -a_(n-2)
+    check_homogeneous(&[1.0, 0.0], &[0.0, -1.0], 20);
+    // 三重根 (x-1)³, a_n = n²
+    let cf = recurrence_closed_form(&[0.0, 1.0, 4.0], &[3.0, -3.0, 1.0]).unwrap();
+    for n in 0..20 {
+        assert!(close(cf.evaluate(n), (n * n) as f64), "{} {}", n, cf);
+    }
+    assert!(recurrence_term(&[], &[], 0).is_err());
+}
+
+#[test]
+fn nonhomogeneous_closed_forms() {
+    // a_n = 2a_(n-1) + n·3^n, a_0 = 1
+    let cf = recurrence_closed_form_nonhomogeneous(&[1.0], &[2.0], &[0.0, 1.0], 3.0).unwrap();
+    let mut a = 1.0;
+    for n in 0..15 {
+        if n > 0 {
+            a = 2.0 * a + n as f64 * 3f64.powi(n as i32);
+        }
+        assert!(close(cf.evaluate(n), a), "{} {} {}", n, cf, a);
+    }
+    // 共振情形: a_n = 2a_(n-1) + 2^n
+    let cf = recurrence_closed_form_nonhomogeneous(&[1.0], &[2.0], &[1.0], 2.0).unwrap();
+    let mut a = 1.0;
+    for n in 0..15 {
+        if n > 0 {
+            a = 2.0 * a + 2f64.powi(n as i32);
+        }
+        assert!(close(cf.evaluate(n), a), "{} {}", n, cf);
+    }
+}
+
+#[test]
+fn first_order_closed_forms() {
+    let cf = first_order_closed_form(1.0, 2.0, 1.0).unwrap();
+    for n in 1..10 {
+        assert!(close(cf.evaluate(n), 2f64.powi(n as i32) - 1.0));
+    }
+    let cf = first_order_closed_form(1.0, 1.0, 3.0).unwrap();
+    assert!(close(cf.evaluate(5), 13.0));
+    let cf = first_order_exponential_closed_form(1.0, 2.0, 1.0, 3.0).unwrap();
+    let mut a = 1.0;
+    for n in 1..10 {
+        assert!(close(cf.evaluate(n), a));
+        a = 2.0 * a + 3f64.powi(n as i32);
+    }
+    let cf = first_order_exponential_closed_form(2.0, 2.0, 2.0, 2.0).unwrap();
+    let mut a = 2.0;
+    for n in 1..10 {
+        assert!(close(cf.evaluate(n), a), "{}", cf);
+        a = 2.0 * a + 2.0 * 2f64.powi(n as i32);
+    }
+    // 一阶递推的通项从 a_1 起计, 特征根法的通项从 a_0 起计
+    assert_eq!(first_order_closed_form(1.0, 2.0, 1.0).unwrap().start, 1);
+    assert_eq!(recurrence_closed_form(&[1.0], &[2.0]).unwrap().start, 0);
+    assert!(first_order_closed_form(1.0, 2.0, 1.0).unwrap().evaluate(0).is_nan());
+    assert_eq!(first_order_closed_form(3.0, 2.0, 0.0).unwrap().to_string(), "a_n = 3·2^(n-1)");
+}
+
+#[test]
+fn first_order_with_zero_multiplier() {
+    // a_(n+1) = q: a_1 之后各项恒为 q
+    let cf = first_order_closed_form(5.0, 0.0, 2.0).unwrap();
+    assert!(close(cf.evaluate(1), 5.0), "{}", cf);
+    for n in 2..8 {
+        assert!(close(cf.evaluate(n), 2.0), "{} {}", n, cf);
+    }
+    // a_(n+1) = q·r^n
+    let cf = first_order_exponential_closed_form(5.0, 0.0, 2.0, 3.0).unwrap();
+    assert!(close(cf.evaluate(1), 5.0), "{}", cf);
+    for n in 2..8 {
+        assert!(close(cf.evaluate(n), 2.0 * 3f64.powi(n as i32 - 1)), "{} {}", n, cf);
+    }
+}
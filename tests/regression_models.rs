@@ -0,0 +1,36 @@
+use guhs::maths::probability::regression::statistics::*;
+
+#[test]
+fn linear() {
+    let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let y = [2.1, 3.9, 6.2, 7.8, 10.1];
+    let fit = linear_fit(&x, &y).unwrap();
+    assert!(fit.r > 0.99 && (fit.r_squared - fit.r * fit.r).abs() < 1e-12);
+    assert!(fit.residuals.iter().sum::<f64>().abs() < 1e-9);
+    let r2 = coefficient_of_determination(&y, &x.iter().map(|&t| fit.slope * t + fit.intercept).collect::<Vec<_>>()).unwrap();
+    assert!((r2 - fit.r_squared).abs() < 1e-9);
+    assert!(pearson_r(&x, &[1.0; 5]).is_err());
+    let x2: Vec<f64> = (1..=12).map(|t| t as f64).collect();
+    let mut y2: Vec<f64> = x2.iter().enumerate().map(|(i, t)| 2.0 * t + if i % 2 == 0 { 0.2 } else { -0.2 }).collect();
+    // 第 7 个点偏离直线 5 个单位
+    y2[6] += 5.0;
+    let sr = standardized_residuals(&x2, &y2, 2.0).unwrap();
+    assert!(sr[6].outlier && sr.iter().filter(|s| s.outlier).count() == 1, "{:?}", sr);
+}
+
+#[test]
+fn nonlinear() {
+    let x: Vec<f64> = (1..=6).map(|t| t as f64).collect();
+    let y: Vec<f64> = x.iter().map(|t| 3.0 * (0.5 * t).exp()).collect();
+    let f = fit_model(RegressionModel::Exponential, &x, &y).unwrap();
+    assert!((f.a - 3.0).abs() < 1e-9 && (f.b - 0.5).abs() < 1e-9 && f.r_squared > 0.999999);
+    let best = compare_models(&x, &y).unwrap();
+    assert_eq!(best[0].model, RegressionModel::Exponential);
+    let y: Vec<f64> = x.iter().map(|t| 2.0 * t.powf(1.5)).collect();
+    assert_eq!(compare_models(&x, &y).unwrap()[0].model, RegressionModel::Power);
+    let y: Vec<f64> = x.iter().map(|t| 1.0 - 4.0 / t).collect();
+    let best = compare_models(&x, &y).unwrap();
+    assert_eq!(best[0].model, RegressionModel::Reciprocal);
+    assert_eq!(best[0].to_string(), "ŷ = 1.0000 - 4.0000/x");
+    assert!(fit_model(RegressionModel::Power, &[-1.0, 2.0], &[1.0, 2.0]).is_err());
+}
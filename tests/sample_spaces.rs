@@ -0,0 +1,75 @@
+use guhs::maths::algebra::fraction::fractions::Fraction;
+use guhs::maths::probability::sample_space::sample_spaces::*;
+
+fn fr(a: i128, b: i128) -> Fraction {
+    Fraction::new(a, b).unwrap()
+}
+
+#[test]
+fn dice_coins() {
+    let s = SampleSpaceBuilder::new().dice(2, 6).build().unwrap();
+    assert_eq!(s.size(), Some(36));
+    assert_eq!(s.probability(|o| o.sum() == 7).unwrap(), fr(1, 6));
+    assert_eq!(s.conditional_probability(|o| o.sum() == 8, |o| o.stage(0)[0] == 3).unwrap(), fr(1, 6));
+    assert!(s.is_independent(|o| o.sum() == 7, |o| o.stage(0)[0] == 1).unwrap());
+    assert!(!s.is_independent(|o| o.sum() == 8, |o| o.stage(0)[0] == 1).unwrap());
+    let c = SampleSpaceBuilder::new().coins(3).build().unwrap();
+    assert_eq!(c.probability(|o| o.count(HEADS) >= 2).unwrap(), fr(1, 2));
+    assert!(c.conditional_probability(|_| true, |_| false).is_err());
+}
+
+#[test]
+fn urns_cards() {
+    // 3 个红球(0)、2 个白球(1), 不放回摸 2 个
+    let u = SampleSpaceBuilder::new().urn(&[(0, 3), (1, 2)], 2, false, false).build().unwrap();
+    assert_eq!(u.size(), Some(10));
+    assert_eq!(u.probability(|o| o.count(0) == 1).unwrap(), fr(3, 5));
+    let uo = SampleSpaceBuilder::new().urn(&[(0, 3), (1, 2)], 2, false, true).build().unwrap();
+    assert_eq!(uo.size(), Some(20));
+    assert_eq!(uo.probability(|o| o.stage(0)[1] == 0).unwrap(), fr(3, 5));
+    let ur = SampleSpaceBuilder::new().urn(&[(0, 3), (1, 2)], 2, true, false).build().unwrap();
+    assert_eq!(ur.probability(|o| o.count(0) == 1).unwrap(), fr(12, 25));
+    let two = SampleSpaceBuilder::new().cards(2, false, false).build().unwrap();
+    assert_eq!(two.size(), Some(1326));
+    assert_eq!(two.probability(|o| o.values().iter().all(|&c| card_rank(c) == 1)).unwrap(), fr(1, 221));
+    let hand = SampleSpaceBuilder::new().cards(5, false, false).build().unwrap();
+    assert!(!hand.is_enumerated());
+    assert!(hand.probability(|_| true).is_err());
+    let hearts = |c: u32| card_suit(c) == 0;
+    assert_eq!(hand.composition_probability(0, &[(&hearts, 5)]).unwrap(), fr(33, 66640));
+    let aces = |c: u32| card_rank(c) == 1;
+    let kings = |c: u32| card_rank(c) == 13;
+    let p = hand.composition_probability(0, &[(&aces, 3), (&kings, 2)]).unwrap();
+    assert_eq!(p, fr(24, 2598960));
+    // 红心与 A 有公共的红心 A
+    assert!(hand.composition_probability(0, &[(&hearts, 2), (&aces, 1)]).is_err());
+    let ur2 = SampleSpaceBuilder::new().urn(&[(0, 3), (1, 2)], 2, true, false).build().unwrap();
+    assert_eq!(ur2.composition_probability(0, &[(&|v| v == 0, 1)]).unwrap(), fr(12, 25));
+    let big = SampleSpaceBuilder::new().cards(5, false, false).die(6).build().unwrap();
+    assert!(big.stagewise_probability(&[&|_| true, &|v: &[u32]| v[0] == 6]).is_err());
+    let mid = SampleSpaceBuilder::new().cards(2, false, false).dice(4, 6).build().unwrap();
+    let pair = |v: &[u32]| card_rank(v[0]) == card_rank(v[1]);
+    let six = |v: &[u32]| v[0] == 6;
+    let p = mid.stagewise_probability(&[&pair, &six, &|_| true, &|_| true, &|_| true]).unwrap();
+    assert_eq!(p, fr(1, 17) * fr(1, 6));
+}
+
+#[test]
+fn weighted() {
+    let w = SampleSpaceBuilder::new().weighted(&[(0, fr(1, 3)), (1, fr(2, 3))]).weighted(&[(0, fr(1, 3)), (1, fr(2, 3))]).build().unwrap();
+    assert_eq!(w.probability(|o| o.sum() == 1).unwrap(), fr(4, 9));
+    assert!(SampleSpaceBuilder::new().weighted(&[(0, fr(1, 3))]).build().is_err());
+    assert!(SampleSpaceBuilder::new().urn(&[(0, 1)], 2, false, false).build().is_err());
+    assert!(SampleSpaceBuilder::new().build().is_err());
+}
+
+#[test]
+fn overflow_is_reported() {
+    // 5 步的分母之积约为 1e45, 超出 i128
+    let p = fr(1, 1_000_000_007);
+    let stage = [(0, p), (1, Fraction::one() - p)];
+    let builder = (0..5).fold(SampleSpaceBuilder::new(), |b, _| b.weighted(&stage));
+    assert!(builder.build().is_err());
+    let space = SampleSpaceBuilder::new().weighted(&stage).weighted(&stage).build().unwrap();
+    assert_eq!(space.probability(|o| o.sum() == 0).unwrap(), p * p);
+}
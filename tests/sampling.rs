@@ -0,0 +1,33 @@
+use guhs::maths::probability::sampling::sampling::*;
+
+#[test]
+fn simple_and_systematic() {
+    let pop: Vec<u32> = (1..=100).collect();
+    let s = simple_random_sample(&pop, 10, false, 3).unwrap();
+    let mut d = s.clone();
+    d.sort();
+    d.dedup();
+    assert_eq!(d.len(), 10);
+    assert_eq!(s, simple_random_sample(&pop, 10, false, 3).unwrap());
+    assert_eq!(simple_random_sample(&pop, 500, true, 3).unwrap().len(), 500);
+    assert!(simple_random_sample(&pop, 101, false, 3).is_err());
+    let pop: Vec<u32> = (1..=1003).collect();
+    let sys = systematic_sample(&pop, 50, 9).unwrap();
+    assert_eq!(sys.removed.len(), 3);
+    assert_eq!(sys.interval, 20);
+    assert!(sys.start < 20 && sys.items.len() == 50);
+    assert!(sys.indices.iter().all(|i| !sys.removed.contains(i)));
+}
+
+#[test]
+fn stratified() {
+    let a = proportional_allocation(&[150, 45, 5], 20).unwrap();
+    let sizes: Vec<usize> = a.iter().map(|x| x.size).collect();
+    assert_eq!(sizes, vec![15, 5, 0]);
+    assert!(a[1].rounded_up && !a[2].rounded_up);
+    let a = proportional_allocation(&[1, 1, 1], 2).unwrap();
+    assert_eq!(a.iter().map(|x| x.size).sum::<usize>(), 2);
+    let strata = vec![vec!["a"; 30], vec!["b"; 20], vec!["c"; 50]];
+    let s = stratified_sample(&strata, 10, 5).unwrap();
+    assert_eq!(s.iter().map(|x| x.items.len()).collect::<Vec<_>>(), vec![3, 2, 5]);
+}
@@ -0,0 +1,65 @@
+use guhs::maths::algebra::identify::sequence_identification::*;
+
+#[test]
+fn kinds() {
+    let a = identify_sequence(&[3.0, 5.0, 7.0, 9.0, 11.0]).unwrap();
+    assert!(matches!(a.pattern, SequencePattern::Arithmetic { .. }) && a.verified);
+    assert_eq!(a.pattern.to_string(), "a_n = 2n + 1");
+    let g = identify_sequence(&[3.0, 6.0, 12.0, 24.0, 48.0]).unwrap();
+    assert_eq!(g.pattern.to_string(), "a_n = 3·2^(n-1)");
+    assert_eq!(g.pattern.evaluate(6).unwrap(), 96.0);
+    let p = identify_sequence(&[1.0, 4.0, 9.0, 16.0, 25.0, 36.0]).unwrap();
+    assert_eq!(p.pattern.to_string(), "a_n = n^2");
+    let fib = identify_sequence(&[1.0, 1.0, 2.0, 3.0, 5.0, 8.0, 13.0, 21.0, 34.0]).unwrap();
+    assert!(fib.verified);
+    assert_eq!(fib.pattern.evaluate(10).unwrap(), 55.0);
+    assert_eq!(fib.pattern.term(10).unwrap().to_f64(), 55.0);
+    let h = identify_sequence(&[0.5, 0.25, 0.125, 0.0625]).unwrap();
+    assert!(h.verified && h.max_error == 0.0);
+    assert_eq!(h.pattern.evaluate(5).unwrap(), 0.03125);
+    // a_n = 2^n + 1 满足 a_n = 3a_(n-1) - 2a_(n-2)
+    let mixed = identify_sequence(&[3.0, 5.0, 9.0, 17.0, 33.0, 65.0, 129.0, 257.0]).unwrap();
+    assert!(mixed.verified);
+    assert_eq!(mixed.pattern.evaluate(9).unwrap(), 513.0);
+    assert!(identify_sequence(&[1.0, 2.0]).is_err());
+}
+
+#[test]
+fn unverified_pattern_reports_held_out_error() {
+    // 前 4 项拟合出的规律与第 5 项相差甚远
+    let bad = identify_sequence(&[1.0, 2.0, 4.0, 7.0, 1.0]).unwrap();
+    assert!(!bad.verified);
+    assert!(bad.max_error > 1.0, "{:?}", bad);
+    let p = identify_sequence(&[1.0, 4.0, 9.0, 16.0, 25.0]).unwrap();
+    assert!(p.verified && p.max_error < 1e-9);
+}
+
+#[test]
+fn sums() {
+    // S_n = n² + 1: a_1 = 2, n >= 2 时 a_n = 2n - 1
+    let s: Vec<f64> = (1..=6).map(|n| (n * n + 1) as f64).collect();
+    let r = identify_from_partial_sums(&s).unwrap();
+    assert!(!r.first_term_fits);
+    let s: Vec<f64> = (1..=6).map(|n| (n * n) as f64).collect();
+    let r = identify_from_partial_sums(&s).unwrap();
+    assert!(r.first_term_fits);
+    assert_eq!(r.term.pattern.to_string(), "a_n = 2n - 1");
+}
+
+#[test]
+fn long_inputs_do_not_overflow() {
+    // 项数较多且无规律时, Berlekamp-Massey 的中间分数会超出 i128
+    let terms: Vec<f64> = (1..=40).map(|k| 1.0 / (k * k + 1) as f64).collect();
+    match identify_sequence(&terms) {
+        Ok(id) => assert!(!id.verified, "{}", id.pattern),
+        Err(e) => assert!(!e.is_empty()),
+    }
+    // 真正的线性递推在项数较多时仍能识别
+    let mut fib = vec![1.0, 1.0];
+    while fib.len() < 60 {
+        let n = fib.len();
+        fib.push(fib[n - 1] + fib[n - 2]);
+    }
+    let id = identify_sequence(&fib[..40]).unwrap();
+    assert!(id.verified, "{}", id.pattern);
+}
@@ -0,0 +1,61 @@
+use guhs::maths::algebra::sequence::sequences::*;
+
+#[test]
+fn trait_basics() {
+    let a = ArithmeticSequence::new(1.0, 2.0);
+    assert_eq!(a.terms().take(4).collect::<Vec<_>>(), vec![1.0, 3.0, 5.0, 7.0]);
+    assert_eq!(a.partial_sum(10).unwrap(), 100.0);
+    assert_eq!(a.monotonicity(1, 10).unwrap(), Monotonicity::Increasing);
+    let g = GeometricSequence::new(1.0, -0.5).unwrap();
+    assert_eq!(g.monotonicity(1, 5).unwrap(), Monotonicity::NotMonotonic);
+    assert!(GeometricSequence::new(1.0, 0.0).is_err());
+    let fib = RecurrenceSequence::new(&[1.0, 1.0], &[1.0, 1.0]).unwrap();
+    assert_eq!(fib.terms().take(7).collect::<Vec<_>>(), vec![1.0, 1.0, 2.0, 3.0, 5.0, 8.0, 13.0]);
+    assert_eq!(fib.term(10).unwrap(), 55.0);
+    assert_eq!(fib.partial_sum(5).unwrap(), 12.0);
+    assert_eq!(fib.monotonicity(1, 6).unwrap(), Monotonicity::NonDecreasing);
+    let c = ClosureSequence::new(|n| (n as f64 - 4.5).powi(2));
+    let b = c.bounds(1, 8).unwrap();
+    assert_eq!((b.argmin, b.argmax), (4, 1));
+    let s = SumDefinedSequence::new(|n| (n * n + 1) as f64);
+    assert!(!s.first_term_matches());
+    assert_eq!(s.terms().take(3).collect::<Vec<_>>(), vec![2.0, 3.0, 5.0]);
+    assert_eq!(s.partial_sum(3).unwrap(), 10.0);
+    let sq = SumDefinedSequence::new(|n| (n * n) as f64);
+    assert!(sq.first_term_matches());
+    let ps = ArithmeticSequence::new(1.0, 2.0).into_partial_sums();
+    assert_eq!(ps.terms().take(3).collect::<Vec<_>>(), vec![1.0, 4.0, 9.0]);
+    assert_eq!(ps.term(4).unwrap(), 16.0);
+    assert!(a.bounds(0, 3).is_err());
+    let k = ClosureSequence::new(|_| 2.0);
+    assert_eq!(k.monotonicity(1, 3).unwrap(), Monotonicity::Constant);
+}
+
+#[test]
+fn rounding_noise_is_not_strict_change() {
+    // 第 3、4 项只差一个舍入误差, 应视为相等
+    let c = ClosureSequence::new(|n| match n {
+        3 => (0.1 + 0.2) * 10.0,
+        4 => 3.0,
+        _ => n as f64,
+    });
+    assert_eq!(c.monotonicity(1, 5).unwrap(), Monotonicity::NonDecreasing);
+}
+
+#[test]
+fn recurrence_closed_form_matches_terms() {
+    let fib = RecurrenceSequence::new(&[1.0, 1.0], &[1.0, 1.0]).unwrap();
+    let cf = fib.closed_form().unwrap();
+    assert_eq!(fib.term(4).unwrap(), 3.0);
+    for n in 1..30 {
+        let expected = fib.term(n).unwrap();
+        assert!((cf.evaluate(n) - expected).abs() < 1e-6 * expected.abs().max(1.0), "n = {}: {}", n, cf);
+    }
+    // 二重根 a_n = 4a_(n-1) - 4a_(n-2)
+    let seq = RecurrenceSequence::new(&[1.0, 4.0], &[4.0, -4.0]).unwrap();
+    let cf = seq.closed_form().unwrap();
+    for n in 1..20 {
+        let expected = seq.term(n).unwrap();
+        assert!((cf.evaluate(n) - expected).abs() < 1e-6 * expected.abs().max(1.0), "n = {}: {}", n, cf);
+    }
+}
@@ -0,0 +1,100 @@
+use guhs::maths::algebra::fraction::fractions::Fraction;
+use guhs::maths::algebra::polynomial::polynomials::Polynomial;
+use guhs::maths::algebra::sequence::sequences::*;
+
+#[test]
+fn power_sums() {
+    let (_, v) = square_sum(10).unwrap();
+    assert_eq!(v, Fraction::from(385));
+    let (_, v) = cube_sum(10).unwrap();
+    assert_eq!(v, Fraction::from(3025));
+    for e in 0..12u32 {
+        let pol = power_sum_polynomial(e).unwrap();
+        for n in 0..15u64 {
+            let direct: i128 = (1..=n as i128).map(|k| k.pow(e)).sum();
+            assert_eq!(pol.evaluate(Fraction::from(n as i64)), Fraction::from_integer(direct), "p={} n={}", e, n);
+        }
+    }
+    let sp = polynomial_sum(&Polynomial::from_integers(&[1, 1])).unwrap();
+    assert_eq!(sp.evaluate(Fraction::from(4)), Fraction::from(14));
+}
+
+#[test]
+fn arithmetic_geometric() {
+    // Σ (2k-1)·2^(k-1)
+    let (s, v) = arithmetic_geometric_sum(1.0, 2.0, 1.0, 2.0, 5).unwrap();
+    let direct: f64 = (1..=5).map(|k| (2 * k - 1) as f64 * 2f64.powi(k - 1)).sum();
+    assert!((v - direct).abs() < 1e-9, "{} {} {}", s, v, direct);
+    assert!((arithmetic_plus_geometric_sum(1.0, 1.0, 1.0, 2.0, 3).unwrap() - 13.0).abs() < 1e-12);
+}
+
+#[test]
+fn telescoping() {
+    let (s, v) = reciprocal_product_sum(2, 10).unwrap();
+    let direct = (1..=10i64).fold(Fraction::zero(), |a, k| a + Fraction::one() / Fraction::from(k * (k + 2)));
+    assert_eq!(v, direct);
+    assert_eq!(s.evaluate(10), Ok(direct));
+    assert_eq!(s.to_string(), "S_n = 1/2·(1 + 1/2 - 1/(n+1) - 1/(n+2))");
+    let (s, v) = reciprocal_product_sum(1, 10).unwrap();
+    assert_eq!(v, Fraction::new(10, 11).unwrap());
+    assert_eq!(s.to_string(), "S_n = 1 - 1/(n+1)");
+
+    let (s, v) = arithmetic_reciprocal_sum(1.0, 2.0, 10).unwrap();
+    assert!((v - 10.0 / 21.0).abs() < 1e-12, "{}", s);
+    assert_eq!(s.to_string(), "S_n = n / (1·(1 + 2n))");
+    // a_k = 5 - 2k: 3, 1, -1, ...
+    let (s, v) = arithmetic_reciprocal_sum(3.0, -2.0, 1).unwrap();
+    assert!((v - 1.0 / 3.0).abs() < 1e-12);
+    assert_eq!(s.to_string(), "S_n = n / (3·(3 - 2n))");
+    // 4, 2, 0 中出现 0
+    assert!(arithmetic_reciprocal_sum(4.0, -2.0, 2).is_err());
+
+    let (s, v) = sqrt_telescoping_sum(99);
+    assert!((v - 9.0).abs() < 1e-12);
+    assert_eq!(s.to_string(), "S_n = √(n+1) - 1");
+}
+
+#[test]
+fn alternating() {
+    let f = Polynomial::from_integers(&[0, 0, 1]);
+    let alt = alternating_sum(&f).unwrap();
+    for n in 0..20u64 {
+        let direct = (1..=n as i64).fold(Fraction::zero(), |a, k| a + Fraction::from(if k % 2 == 1 { k * k } else { -k * k }));
+        assert_eq!(alt.evaluate(n), Ok(direct));
+    }
+}
+
+#[test]
+fn grouped() {
+    // a_k = k, 每 3 项一组: G_m = 9m - 3
+    let k = Polynomial::from_integers(&[0, 1]);
+    let gs = grouped_sum(&[k.clone(), k.clone(), k.clone()]).unwrap();
+    assert_eq!(gs.groups(10), Ok(vec![Fraction::from(6), Fraction::from(15), Fraction::from(24), Fraction::from(10)]));
+    assert_eq!(gs.evaluate(10), Ok(Fraction::from(55)));
+    assert_eq!(gs.group_term, Polynomial::from_integers(&[-3, 9]));
+    // 符号按 +, +, - 循环的 Σ ±k²
+    let sq = Polynomial::from_integers(&[0, 0, 1]);
+    let gs = grouped_sum(&[sq.clone(), sq.clone(), -&sq]).unwrap();
+    for n in 0..20u64 {
+        let direct = (1..=n as i64).fold(Fraction::zero(), |a, k| a + Fraction::from(if k % 3 == 0 { -k * k } else { k * k }));
+        assert_eq!(gs.evaluate(n), Ok(direct), "n = {}", n);
+    }
+    assert!(gs.to_string().starts_with("G_m = "));
+    assert_eq!(gs.term(3), Ok(Fraction::from(-9)));
+    assert!(gs.term(0).is_err());
+    assert!(grouped_sum(&[]).is_err());
+}
+
+#[test]
+fn overflow_is_reported() {
+    // 伯努利数的分子分母很快超出 i128
+    assert!(power_sum_polynomial(60).is_err());
+    assert!(power_sum(3, 1 << 40).is_err());
+    assert!(power_sum(3, 1 << 20).is_ok());
+    let (s, _) = reciprocal_product_sum(3, 10).unwrap();
+    assert!(s.evaluate(u64::MAX / 4).is_err());
+    let k = Polynomial::from_integers(&[0, 0, 0, 1]);
+    let gs = grouped_sum(&[k.clone(), -&k]).unwrap();
+    assert!(gs.evaluate(1 << 50).is_err());
+    assert!(alternating_sum(&k).unwrap().evaluate(1 << 50).is_err());
+}
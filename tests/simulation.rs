@@ -0,0 +1,54 @@
+use guhs::maths::probability::distribution::distributions::*;
+use guhs::maths::probability::probability::probability::*;
+use guhs::maths::probability::simulation::simulation::*;
+
+#[test]
+fn rng() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    assert_eq!(a.next_u64(), b.next_u64());
+    let xs: Vec<f64> = (0..100000).map(|_| a.uniform()).collect();
+    let m = xs.iter().sum::<f64>() / xs.len() as f64;
+    assert!((m - 0.5).abs() < 0.01);
+    let ns: Vec<f64> = (0..100000).map(|_| a.normal(10.0, 2.0).unwrap()).collect();
+    let m = ns.iter().sum::<f64>() / ns.len() as f64;
+    let v = ns.iter().map(|x| (x - m).powi(2)).sum::<f64>() / ns.len() as f64;
+    assert!((m - 10.0).abs() < 0.05 && (v - 4.0).abs() < 0.1);
+    let t = DiscreteTable::new(&[1.0, 2.0, 3.0], &[0.2, 0.5, 0.3]).unwrap();
+    let m = (0..100000).map(|_| t.sample(&mut a)).sum::<f64>() / 100000.0;
+    assert!((m - 2.1).abs() < 0.02);
+    assert!(a.below(0).is_err());
+    let mut v = [1, 2, 3, 4, 5];
+    a.shuffle(&mut v);
+    v.sort();
+    assert_eq!(v, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn monte_carlo() {
+    let mc = MonteCarlo::new(200000, 7).unwrap();
+    // 三个工厂产品, 次品率不同
+    let parts = [0.25, 0.35, 0.4];
+    let defect = [0.05, 0.04, 0.02];
+    let total = total_probability(&parts, &defect).unwrap();
+    let table = DiscreteTable::new(&[0.0, 1.0, 2.0], &parts).unwrap();
+    let est = mc
+        .probability(|r| {
+            let i = table.sample_index(r);
+            r.bernoulli(defect[i]).unwrap()
+        })
+        .unwrap();
+    assert!(est.contains(total), "{:?} {}", est, total);
+    let posterior = bayes_theorem(0.25, 0.05, total).unwrap();
+    let est = mc
+        .conditional_probability(|r| {
+            let i = table.sample_index(r);
+            (r.bernoulli(defect[i]).unwrap(), i == 0)
+        })
+        .unwrap();
+    assert!(est.contains(posterior), "{:?} {}", est, posterior);
+    let b = Binomial::new(10, 0.3).unwrap();
+    let est = mc.expectation(|r| (0..10).filter(|_| r.bernoulli(0.3).unwrap()).count() as f64).unwrap();
+    assert!(est.contains(b.mean()), "{:?}", est);
+    assert!(MonteCarlo::new(0, 1).is_err() && mc.with_confidence(1.0).is_err());
+}
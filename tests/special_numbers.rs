@@ -0,0 +1,71 @@
+use guhs::maths::discrete::biguint::big_integers::*;
+use guhs::maths::discrete::counting::combinatorics;
+use guhs::maths::discrete::special::special_numbers::*;
+
+#[test]
+fn big() {
+    let a = BigUint::from(u64::MAX);
+    assert_eq!((&a * &a).to_string(), "340282366920938463426481119284349108225");
+    assert_eq!((&a + &BigUint::one()).to_string(), "18446744073709551616");
+    assert_eq!(BigUint::from(2).pow(100).to_string(), "1267650600228229401496703205376");
+    assert!(BigUint::from(3).pow(40) > BigUint::from(2).pow(63));
+    assert_eq!(BigUint::zero().to_string(), "0");
+    assert_eq!(BigUint::from(1_000_000_000).to_string(), "1000000000");
+}
+
+#[test]
+fn numbers() {
+    let mut c = CountingNumbers::new();
+    assert_eq!(c.factorial(30).to_string(), "265252859812191058636308480000000");
+    assert_eq!(c.binomial(100, 50).to_u64(), None);
+    assert_eq!(c.binomial(100, 50).to_string(), combinatorics::binomial(100, 50).unwrap().to_string());
+    assert_eq!(c.multinomial(&[3, 2, 1]).to_u64(), Some(60));
+    assert_eq!(c.stirling_second(5, 3).to_u64(), Some(25));
+    assert_eq!(c.stirling_first(5, 2).to_u64(), Some(50));
+    let bells: Vec<u64> = (0..8).map(|n| c.bell(n).to_u64().unwrap()).collect();
+    assert_eq!(bells, vec![1, 1, 2, 5, 15, 52, 203, 877]);
+    let cats: Vec<u64> = (0..8).map(|n| c.catalan(n).to_u64().unwrap()).collect();
+    assert_eq!(cats, vec![1, 1, 2, 5, 14, 42, 132, 429]);
+    assert_eq!(c.partition_count(100).to_u64(), Some(190569292));
+    assert_eq!(c.partitions(7, 3).to_u64(), Some(4));
+    assert_eq!(c.bell(30).to_string(), "846749014511809332450147");
+    assert_eq!(c.catalan(50).to_string(), "1978261657756160653623774456");
+}
+
+#[test]
+fn agrees_with_checked_u128_helpers() {
+    let mut c = CountingNumbers::new();
+    for n in 0..30u64 {
+        for k in 0..=n + 1 {
+            let (un, uk) = (n as usize, k as usize);
+            assert_eq!(c.binomial(un, uk).to_string(), combinatorics::binomial(n, k).unwrap().to_string());
+            assert_eq!(c.falling_factorial(un, uk).to_string(), combinatorics::falling_factorial(n, k).unwrap().to_string());
+        }
+    }
+    assert_eq!(c.multinomial(&[4, 3, 2, 1]).to_string(), combinatorics::multinomial(&[4, 3, 2, 1]).unwrap().to_string());
+}
+
+#[test]
+fn twelvefold() {
+    let mut c = CountingNumbers::new();
+    let t = c.twelvefold_table(5, 3);
+    assert!(t.to_string().starts_with("n = 5, k = 3"));
+    let g = |b, x, r| t.get(b, x, r).to_u64().unwrap();
+    use Balls as B;
+    use Boxes as X;
+    use Rule as R;
+    assert_eq!(g(B::Distinct, X::Distinct, R::Any), 243);
+    assert_eq!(g(B::Distinct, X::Distinct, R::AtMostOne), 0);
+    assert_eq!(g(B::Distinct, X::Distinct, R::AtLeastOne), 150);
+    assert_eq!(g(B::Distinct, X::Identical, R::Any), 41);
+    assert_eq!(g(B::Distinct, X::Identical, R::AtLeastOne), 25);
+    assert_eq!(g(B::Identical, X::Distinct, R::Any), 21);
+    assert_eq!(g(B::Identical, X::Distinct, R::AtLeastOne), 6);
+    assert_eq!(g(B::Identical, X::Identical, R::Any), 5);
+    assert_eq!(g(B::Identical, X::Identical, R::AtLeastOne), 2);
+    let t = c.twelvefold_table(2, 4);
+    assert_eq!(t.get(B::Distinct, X::Distinct, R::AtMostOne).to_u64(), Some(12));
+    assert_eq!(t.get(B::Identical, X::Distinct, R::AtMostOne).to_u64(), Some(6));
+    let t = c.twelvefold_table(0, 0);
+    assert!(t.entries.iter().flatten().flatten().all(|v| v.to_u64() == Some(1)));
+}